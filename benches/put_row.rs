@@ -0,0 +1,141 @@
+// Throughput of PutRow against a local server which responds immediately,
+// with requests issued directly, and through a stand-in of the former actor,
+// i.e. one task behind `mpsc::channel(1)` which spawns a task per request.
+//
+//     cargo bench --bench put_row
+use tablestore as ots;
+use std::convert::Infallible;
+use std::time::Instant;
+use tokio::sync::{mpsc, oneshot};
+
+const REQUESTS_PER_ROUND: usize = 20_000;
+const ROUNDS: usize = 5;
+const CONCURRENCY_LEVELS: [usize; 4] = [1, 16, 256, 1024];
+
+async fn serve() -> ots::Endpoint {
+    let make_svc = hyper::service::make_service_fn(|_| async {
+        Ok::<_, Infallible>(hyper::service::service_fn(|_req| async {
+            Ok::<_, Infallible>(hyper::Response::new(hyper::Body::empty()))
+        }))
+    });
+    let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_svc);
+    let addr = server.local_addr();
+    tokio::spawn(server);
+    ots::Endpoint::new(format!("http://{}", addr), "bench").unwrap()
+}
+
+fn request(i: usize) -> ots::PutRowRequest {
+    let row = ots::Row{
+        row_key: ots::RowKey::new(vec![ots::RowKeyColumn{
+            name: ots::Name::new("pkey"),
+            value: ots::RowKeyValue::Int(i as i64),
+        }]),
+        attrs: vec![ots::Attribute{
+            name: ots::Name::new("attr"),
+            value: ots::AttrValue::Str("value".to_string()),
+            timestamp: ots::AttrTimestamp::ServerAttach,
+        }],
+    };
+    ots::PutRowRequest::new("bench", row).unwrap()
+}
+
+type Cmd = (ots::PutRowRequest, oneshot::Sender<Result<ots::PutRowResponse, ots::Error>>);
+
+#[derive(Clone)]
+enum Issuer {
+    Direct(ots::Client),
+    Actor(mpsc::Sender<Cmd>),
+}
+
+impl Issuer {
+    fn actor(client: ots::Client) -> Issuer {
+        let (tx, mut rx) = mpsc::channel::<Cmd>(1);
+        tokio::spawn(async move {
+            while let Some((req, resp_tx)) = rx.recv().await {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    let _ = resp_tx.send(client.put_row(req).await);
+                });
+            }
+        });
+        Issuer::Actor(tx)
+    }
+
+    async fn put_row(&self, req: ots::PutRowRequest) -> Result<ots::PutRowResponse, ots::Error> {
+        match self {
+            Issuer::Direct(client) => client.put_row(req).await,
+            Issuer::Actor(tx) => {
+                let (resp_tx, resp_rx) = oneshot::channel();
+                tx.clone().send((req, resp_tx)).await.unwrap();
+                resp_rx.await.unwrap()
+            }
+        }
+    }
+}
+
+async fn put_rows(issuer: Issuer, workers: usize) -> f64 {
+    let start = Instant::now();
+    let handles: Vec<_> = (0..workers)
+        .map(|w| {
+            let issuer = issuer.clone();
+            tokio::spawn(async move {
+                for i in (w..REQUESTS_PER_ROUND).step_by(workers) {
+                    issuer.put_row(request(i)).await.unwrap();
+                }
+            })
+        })
+        .collect();
+    for h in handles {
+        h.await.unwrap();
+    }
+    REQUESTS_PER_ROUND as f64 / start.elapsed().as_secs_f64()
+}
+
+fn median(mut xs: Vec<f64>) -> f64 {
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    xs[xs.len() / 2]
+}
+
+fn main() {
+    let mut rt = tokio::runtime::Builder::new()
+        .threaded_scheduler()
+        .enable_all()
+        .build()
+        .unwrap();
+    rt.block_on(async {
+        let ep = serve().await;
+        let cred = ots::Credential::new("id", "secret").unwrap();
+        let opts = ots::ClientOptions{
+            concurrency: CONCURRENCY_LEVELS[CONCURRENCY_LEVELS.len() - 1] as i64,
+            ..Default::default()
+        };
+        let client = ots::Client::new(ep, cred, opts).unwrap();
+        let direct = Issuer::Direct(client.clone());
+        let actor = Issuer::actor(client);
+        // fill the connection pool step by step, so that connecting is excluded
+        for &workers in CONCURRENCY_LEVELS.iter() {
+            put_rows(direct.clone(), workers).await;
+        }
+        for &workers in CONCURRENCY_LEVELS.iter() {
+            let mut directs = vec![];
+            let mut actors = vec![];
+            for round in 0..ROUNDS {
+                // alternate the order, so that neither always runs on a warmer process
+                if round % 2 == 0 {
+                    directs.push(put_rows(direct.clone(), workers).await);
+                    actors.push(put_rows(actor.clone(), workers).await);
+                } else {
+                    actors.push(put_rows(actor.clone(), workers).await);
+                    directs.push(put_rows(direct.clone(), workers).await);
+                }
+            }
+            let direct = median(directs);
+            let actor = median(actors);
+            println!("put_row\tconcurrency={}\tdirect={:.0}/s\tactor={:.0}/s\tratio={:.2}",
+                workers,
+                direct,
+                actor,
+                direct / actor);
+        }
+    });
+}
@@ -1,11 +1,22 @@
-use crate::{Endpoint, Credential, ClientOptions, Error, types};
+use bytes::Bytes;
+use crate::{Endpoint, Credential, ClientOptions, Timeouts, Error, ErrorCode, types};
+use crate::{DeadlineRetryStrategy, RetryCategory, RetryStrategy};
 use crate::client_impl;
+use crate::types::BatchResponse;
 use log::*;
-use tokio::sync::{mpsc, oneshot};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::stream::Stream;
+use tokio::sync::mpsc;
 
 #[derive(Clone)]
 pub struct Client {
-    cmd_sender: mpsc::Sender<client_impl::Cmd>,
+    inner: Arc<client_impl::ClientImpl>,
+    retry_strategy: Box<dyn RetryStrategy + Send + Sync>,
+    timeouts: Timeouts,
 }
 
 impl Client {
@@ -14,30 +25,116 @@ impl Client {
         credential: Credential,
         opts: ClientOptions,
     ) -> Result<Client, Error> {
-        let tx = client_impl::ClientImpl::new(endpoint, credential, opts);
+        let retry_strategy = opts.retry_strategy.clone();
+        let timeouts = opts.timeouts;
+        let inner = client_impl::ClientImpl::new(endpoint, credential, opts)?;
         let res = Client{
-            cmd_sender: tx,
+            inner: Arc::new(inner),
+            retry_strategy,
+            timeouts,
         };
         Ok(res)
     }
 
+    // a client sharing connections with this one, but issuing requests under other timeouts
+    pub fn with_timeouts(&self, timeouts: Timeouts) -> Client {
+        let mut res = self.clone();
+        res.timeouts = timeouts;
+        res
+    }
+
+    pub fn timeouts(&self) -> Timeouts {
+        self.timeouts
+    }
+
+    // total capacity units consumed by all requests through this client
+    pub fn consumed_capacity(&self) -> types::ConsumedCapacity {
+        self.inner.consumed_capacity()
+    }
+
     pub async fn list_table(&self) -> Result<types::ListTableResponse, Error> {
         debug!("Issue ListTable");
         let req = types::ListTableRequest{};
-        let (tx, rx) = oneshot::channel();
-        let cmd = client_impl::Cmd::ListTable(req, tx);
-        self.cmd_sender.clone().send(cmd).await.unwrap();
-        rx.await.unwrap()
+        self.inner.call(req, self.timeouts).await
     }
 
     pub async fn create_table(
         &self,
         req: types::CreateTableRequest,
     ) -> Result<types::CreateTableResponse, Error> {
-        let (tx, rx) = oneshot::channel();
-        let cmd = client_impl::Cmd::CreateTable(req, tx);
-        self.cmd_sender.clone().send(cmd).await.unwrap();
-        rx.await.unwrap()
+        req.check_split_points()?;
+        let name = req.table_meta.name.clone();
+        let wait = req.wait_until_active;
+        let resp = self.inner.call(req, self.timeouts).await?;
+        if let Some(timeout) = wait {
+            self.wait_until_active(String::from(name), timeout).await?;
+        }
+        Ok(resp)
+    }
+
+    pub async fn wait_until_active<T: ToString>(
+        &self,
+        name: T,
+        timeout: std::time::Duration,
+    ) -> Result<types::TableDescription, Error> {
+        self.wait_for_status(name.to_string(), types::TableStatus::Active, timeout).await
+    }
+
+    pub async fn wait_until_inactive<T: ToString>(
+        &self,
+        name: T,
+        timeout: std::time::Duration,
+    ) -> Result<types::TableDescription, Error> {
+        self.wait_for_status(name.to_string(), types::TableStatus::Inactive, timeout).await
+    }
+
+    async fn wait_for_status(
+        &self,
+        name: String,
+        status: types::TableStatus,
+        timeout: std::time::Duration,
+    ) -> Result<types::TableDescription, Error> {
+        let act = types::Action::DescribeTable;
+        let mut retry = DeadlineRetryStrategy::new(timeout);
+        let deadline = Instant::now() + timeout;
+        loop {
+            let left = deadline.saturating_duration_since(Instant::now());
+            let total = match self.timeouts.total {
+                Some(x) => std::cmp::min(x, left),
+                None => left,
+            };
+            let client = self.with_timeouts(Timeouts{
+                total: Some(total),
+                ..self.timeouts
+            });
+            let desc = client.describe_table(name.clone()).await?.description;
+            if desc.status == status {
+                return Ok(desc);
+            }
+            let err = Error{
+                code: ErrorCode::OTSTableNotReady,
+                message: format!("Table {} is {:?}.", name, desc.status),
+            };
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left == Duration::from_secs(0) {
+                return Err(err);
+            }
+            match retry.next_pause(act, &err) {
+                None => return Err(err),
+                Some(dur) => {
+                    let dur = std::cmp::min(dur, left);
+                    debug!("Wait for table to be {:?}.\
+                        \ttable: {}\
+                        \tstatus: {:?}\
+                        \tdelay: {:?}",
+                        status,
+                        name,
+                        desc.status,
+                        dur);
+                    tokio::time::delay_for(dur).await;
+                }
+            }
+        }
     }
 
     pub async fn delete_table<T: ToString>(
@@ -47,20 +144,600 @@ impl Client {
         let req = types::DeleteTableRequest{
             name: types::Name::new(name),
         };
-        let (tx, rx) = oneshot::channel();
-        let cmd = client_impl::Cmd::DeleteTable(req, tx);
-        self.cmd_sender.clone().send(cmd).await.unwrap();
-        rx.await.unwrap()
+        self.inner.call(req, self.timeouts).await
+    }
+
+    pub async fn describe_table<T: ToString>(
+        &self,
+        name: T,
+    ) -> Result<types::DescribeTableResponse, Error> {
+        let req = types::DescribeTableRequest{
+            name: types::Name::new(name),
+        };
+        self.inner.call(req, self.timeouts).await
+    }
+
+    pub async fn update_table(
+        &self,
+        req: types::UpdateTableRequest,
+    ) -> Result<types::UpdateTableResponse, Error> {
+        self.inner.call(req, self.timeouts).await
+    }
+
+    pub async fn load_table(
+        &self,
+        req: types::LoadTableRequest,
+    ) -> Result<types::LoadTableResponse, Error> {
+        let name = String::from(req.name.clone());
+        let wait = req.wait_until_active;
+        let resp = self.inner.call(req, self.timeouts).await?;
+        if let Some(timeout) = wait {
+            self.wait_until_active(name, timeout).await?;
+        }
+        Ok(resp)
+    }
+
+    pub async fn unload_table(
+        &self,
+        req: types::UnloadTableRequest,
+    ) -> Result<types::UnloadTableResponse, Error> {
+        let name = String::from(req.name.clone());
+        let wait = req.wait_until_inactive;
+        let resp = self.inner.call(req, self.timeouts).await?;
+        if let Some(timeout) = wait {
+            self.wait_until_inactive(name, timeout).await?;
+        }
+        Ok(resp)
     }
 
     pub async fn put_row(
         &self,
         req: types::PutRowRequest,
     ) -> Result<types::PutRowResponse, Error> {
-        let (tx, rx) = oneshot::channel();
-        let cmd = client_impl::Cmd::PutRow(req, tx);
-        self.cmd_sender.clone().send(cmd).await.unwrap();
-        rx.await.unwrap()
+        self.inner.call(req, self.timeouts).await
+    }
+
+    pub async fn get_row(
+        &self,
+        req: types::GetRowRequest,
+    ) -> Result<types::GetRowResponse, Error> {
+        self.inner.call(req, self.timeouts).await
+    }
+
+    // Columns of a wide row come in chunks, each of which is a row of the same row key.
+    pub fn get_wide_row(
+        &self,
+        req: types::GetRowRequest,
+    ) -> impl Stream<Item=Result<types::Row, Error>> {
+        let (tx, rx) = mpsc::channel(1);
+        let client = self.clone();
+        tokio::spawn(async move {
+            client.scan_row(req, tx).await;
+        });
+        rx
+    }
+
+    async fn scan_row(
+        &self,
+        mut req: types::GetRowRequest,
+        mut tx: mpsc::Sender<Result<types::Row, Error>>,
+    ) {
+        loop {
+            let resp = match self.get_row(req.clone()).await {
+                Ok(resp) => resp,
+                Err(err) => {
+                    let _ = tx.send(Err(err)).await;
+                    return;
+                }
+            };
+            debug!("GetRow got a chunk.\
+                \tcolumns: {:?}\
+                \tnext_token: {:?}",
+                resp.row.as_ref().map(|x| x.attrs.len()),
+                resp.next_token);
+            if let Some(row) = resp.row {
+                if tx.send(Ok(row)).await.is_err() {
+                    debug!("GetRow stream is dropped.");
+                    return;
+                }
+            }
+            match resp.next_token {
+                Some(token) => {
+                    req.token = Some(token);
+                }
+                None => {
+                    return;
+                }
+            }
+        }
+    }
+
+    pub async fn update_row(
+        &self,
+        req: types::UpdateRowRequest,
+    ) -> Result<types::UpdateRowResponse, Error> {
+        self.inner.call(req, self.timeouts).await
+    }
+
+    pub async fn delete_row(
+        &self,
+        req: types::DeleteRowRequest,
+    ) -> Result<types::DeleteRowResponse, Error> {
+        self.inner.call(req, self.timeouts).await
+    }
+
+    pub fn get_range(
+        &self,
+        req: types::GetRangeRequest,
+    ) -> impl Stream<Item=Result<types::Row, Error>> {
+        let (tx, rx) = mpsc::channel(1);
+        let client = self.clone();
+        tokio::spawn(async move {
+            client.scan_range(req, tx).await;
+        });
+        rx
+    }
+
+    async fn get_range_page(
+        &self,
+        req: types::GetRangeRequest,
+    ) -> Result<types::GetRangeResponse, Error> {
+        self.inner.call(req, self.timeouts).await
+    }
+
+    // returns false iff the scan breaks, by an error or dropping the receiver
+    async fn scan_range(
+        &self,
+        mut req: types::GetRangeRequest,
+        mut tx: mpsc::Sender<Result<types::Row, Error>>,
+    ) -> bool {
+        let mut remaining = req.limit;
+        // a row wider than one response is split, and resumed with next_token
+        let mut partial: Option<types::Row> = None;
+        loop {
+            if remaining == Some(0) {
+                return true;
+            }
+            req.limit = remaining;
+            let resp = match self.get_range_page(req.clone()).await {
+                Ok(resp) => resp,
+                Err(err) => {
+                    let _ = tx.send(Err(err)).await;
+                    return false;
+                }
+            };
+            debug!("GetRange got a page.\
+                \trows: {}\
+                \tnext_start: {:?}",
+                resp.rows.len(),
+                resp.next_start);
+            for row in resp.rows.into_iter() {
+                match partial.as_mut() {
+                    Some(x) if x.row_key == row.row_key => {
+                        x.attrs.extend(row.attrs);
+                        continue;
+                    }
+                    _ => {}
+                }
+                if let Some(x) = partial.replace(row) {
+                    if !emit(&mut tx, x, &mut remaining).await {
+                        return remaining == Some(0);
+                    }
+                }
+            }
+            if resp.next_token.is_none() {
+                if let Some(x) = partial.take() {
+                    if !emit(&mut tx, x, &mut remaining).await {
+                        return remaining == Some(0);
+                    }
+                }
+            }
+            match resp.next_start {
+                Some(next_start) => {
+                    req.inclusive_start = next_start;
+                    req.token = resp.next_token;
+                }
+                None => {
+                    if let Some(x) = partial.take() {
+                        if !emit(&mut tx, x, &mut remaining).await {
+                            return remaining == Some(0);
+                        }
+                    }
+                    return true;
+                }
+            }
+        }
+    }
+
+    // The range of `req` is cut by split points of the table, and scanned in parallel.
+    // Rows come in no particular order, so `req` must be forward, without limit or token.
+    pub fn parallel_scan(
+        &self,
+        req: types::GetRangeRequest,
+        split_size: i64, // in 100MB
+        concurrency: usize,
+    ) -> impl Stream<Item=Result<types::Row, Error>> {
+        let concurrency = std::cmp::max(concurrency, 1);
+        let (tx, rx) = mpsc::channel(concurrency);
+        let client = self.clone();
+        tokio::spawn(async move {
+            client.scan_splits(req, split_size, concurrency, tx).await;
+        });
+        rx
+    }
+
+    async fn scan_splits(
+        &self,
+        req: types::GetRangeRequest,
+        split_size: i64,
+        concurrency: usize,
+        mut tx: mpsc::Sender<Result<types::Row, Error>>,
+    ) {
+        if req.direction != types::Direction::Forward || req.limit.is_some() || req.token.is_some() {
+            let err = Error{
+                code: ErrorCode::ClientUnknown,
+                message: "Parallel scan supports neither backward direction, limit nor token.".to_string(),
+            };
+            let _ = tx.send(Err(err)).await;
+            return;
+        }
+        let resp = match self.compute_split_points(<&str>::from(&req.table_name), split_size).await {
+            Ok(resp) => resp,
+            Err(err) => {
+                let _ = tx.send(Err(err)).await;
+                return;
+            }
+        };
+        let ranges = match intersect_ranges(
+            resp.ranges,
+            &req.inclusive_start,
+            &req.exclusive_end,
+        ) {
+            Ok(x) => x,
+            Err(err) => {
+                let _ = tx.send(Err(err)).await;
+                return;
+            }
+        };
+        debug!("Scan table in parallel.\
+            \ttable: {:?}\
+            \tsplits: {}\
+            \tconcurrency: {}",
+            req.table_name,
+            ranges.len(),
+            concurrency);
+        let ranges = Arc::new(Mutex::new(ranges));
+        // Workers send rows here, so that the stream ends right after the first error.
+        let (worker_tx, mut worker_rx) = mpsc::channel(concurrency);
+        for _ in 0..concurrency {
+            let client = self.clone();
+            let ranges = ranges.clone();
+            let mut req = req.clone();
+            let tx = worker_tx.clone();
+            tokio::spawn(async move {
+                loop {
+                    let range = ranges.lock().unwrap().pop_front();
+                    let (start, end) = match range {
+                        Some(x) => x,
+                        None => return,
+                    };
+                    req.inclusive_start = start;
+                    req.exclusive_end = end;
+                    if !client.scan_range(req.clone(), tx.clone()).await {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(worker_tx);
+        while let Some(x) = worker_rx.recv().await {
+            let failed = x.is_err();
+            if tx.send(x).await.is_err() || failed {
+                return; // workers stop once worker_rx is dropped
+            }
+        }
+    }
+
+    pub async fn batch_write_row(
+        &self,
+        req: types::BatchWriteRowRequest,
+    ) -> Result<types::BatchWriteRowResponse, Error> {
+        let resp: types::BatchWriteRowResponse = self.inner.call(req.clone(), self.timeouts).await?;
+        resp.check_against(&req)?;
+        if !req.resubmit_failed_rows {
+            return Ok(resp);
+        }
+        self.retry_failed_rows(req, resp).await
+    }
+
+    pub async fn batch_get_row(
+        &self,
+        req: types::BatchGetRowRequest,
+    ) -> Result<types::BatchGetRowResponse, Error> {
+        let resp: types::BatchGetRowResponse = self.inner.call(req.clone(), self.timeouts).await?;
+        resp.check_against(&req)?;
+        self.retry_failed_rows(req, resp).await
+    }
+
+    // Rows failed by retriable errors are issued again in a sub-request,
+    // until none is left or the retry strategy gives up.
+    async fn retry_failed_rows<Req, Resp>(
+        &self,
+        req: Req,
+        mut resp: Resp,
+    ) -> Result<Resp, Error>
+    where
+        Req: types::Request + types::BatchRequest + Into<Bytes> + std::fmt::Debug,
+        Resp: types::Response + BatchResponse<Request=Req> + TryFrom<Vec<u8>, Error=Error> + std::fmt::Debug,
+    {
+        let act = req.action();
+        let mut retry = self.retry_strategy.clone();
+        loop {
+            let mut positions = vec![];
+            let mut first_err = None;
+            for (i, (rows, _)) in resp.tables_mut().into_iter().enumerate() {
+                for (j, row) in rows.iter().enumerate() {
+                    let err = match row {
+                        Err(err) => err,
+                        Ok(_) => continue,
+                    };
+                    if !RetryCategory::calc(err).determine_with_action(act) {
+                        continue;
+                    }
+                    if first_err.is_none() {
+                        first_err = Some(err.clone());
+                    }
+                    positions.push((i, j));
+                }
+            }
+            let dur = match first_err {
+                None => return Ok(resp),
+                Some(err) => match retry.next_pause(act, &err) {
+                    None => return Ok(resp),
+                    Some(dur) => dur,
+                },
+            };
+            info!("Retry failed rows.\
+                \taction: {:?}\
+                \trows: {}\
+                \tdelay: {:?}",
+                act,
+                positions.len(),
+                dur);
+            tokio::time::delay_for(dur).await;
+            let sub_req = req.select(&positions);
+            let mut sub_resp: Resp = match self.inner.call(sub_req.clone(), self.timeouts).await {
+                Ok(x) => x,
+                Err(err) => {
+                    info!("Fail to retry rows.\
+                        \taction: {:?}\
+                        \terror: {:?}",
+                        act,
+                        err);
+                    return Ok(resp);
+                }
+            };
+            sub_resp.check_against(&sub_req)?;
+            let results = sub_resp.tables_mut()
+                .into_iter()
+                .flat_map(|(rows, consumed)| {
+                    std::mem::take(rows).into_iter().zip(std::mem::take(consumed))
+                });
+            let mut tables = resp.tables_mut();
+            for ((i, j), (res, consumed)) in positions.into_iter().zip(results) {
+                tables[i].0[j] = res;
+                tables[i].1[j] += consumed;
+            }
+        }
+    }
+
+    pub async fn compute_split_points<T: ToString>(
+        &self,
+        name: T,
+        split_size: i64, // in 100MB
+    ) -> Result<types::ComputeSplitPointsBySizeResponse, Error> {
+        let req = types::ComputeSplitPointsBySizeRequest{
+            name: types::Name::new(name),
+            split_size,
+        };
+        self.inner.call(req, self.timeouts).await
     }
 }
 
+async fn emit(
+    tx: &mut mpsc::Sender<Result<types::Row, Error>>,
+    row: types::Row,
+    remaining: &mut Option<usize>,
+) -> bool {
+    if *remaining == Some(0) {
+        return false;
+    }
+    if tx.send(Ok(row)).await.is_err() {
+        debug!("GetRange stream is dropped.");
+        return false;
+    }
+    if let Some(x) = remaining.as_mut() {
+        *x -= 1;
+    }
+    true
+}
+
+// split ranges within [start, end), in order
+fn intersect_ranges(
+    ranges: Vec<(types::ExtendedRowKey, types::ExtendedRowKey)>,
+    start: &types::ExtendedRowKey,
+    end: &types::ExtendedRowKey,
+) -> Result<VecDeque<(types::ExtendedRowKey, types::ExtendedRowKey)>, Error> {
+    let incomparable = || {
+        Error{
+            code: ErrorCode::ClientUnknown,
+            message: "Range of parallel scan mismatches the primary key of the table.".to_string(),
+        }
+    };
+    let mut res = VecDeque::new();
+    for (x, y) in ranges.into_iter() {
+        let x = match x.compare(start).ok_or_else(incomparable)? {
+            Ordering::Less => start.clone(),
+            _ => x,
+        };
+        let y = match y.compare(end).ok_or_else(incomparable)? {
+            Ordering::Greater => end.clone(),
+            _ => y,
+        };
+        if x.compare(&y).ok_or_else(incomparable)? == Ordering::Less {
+            res.push_back((x, y));
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+    use crate::plainbuffer::PbufSerde;
+    use crate::protocol as pb;
+    use std::convert::Infallible;
+    use tokio::stream::StreamExt;
+
+    fn int_key(x: types::ExtendedRowKeyValue) -> types::ExtendedRowKey {
+        types::ExtendedRowKey::new(vec![types::ExtendedRowKeyColumn{
+            name: types::Name::new("pk"),
+            value: x,
+        }])
+    }
+
+    fn row(x: i64) -> types::Row {
+        types::Row{
+            row_key: types::RowKey::new(vec![types::RowKeyColumn{
+                name: types::Name::new("pk"),
+                value: types::RowKeyValue::Int(x),
+            }]),
+            attrs: vec![],
+        }
+    }
+
+    #[test]
+    fn parallel_scan_intersects_ranges() {
+        use types::ExtendedRowKeyValue::*;
+        let splits = vec![
+            (int_key(InfMin), int_key(Int(10))),
+            (int_key(Int(10)), int_key(Int(20))),
+            (int_key(Int(20)), int_key(InfMax)),
+        ];
+        let trial = intersect_ranges(splits.clone(), &int_key(Int(5)), &int_key(Int(15))).unwrap();
+        assert_eq!(trial, vec![
+            (int_key(Int(5)), int_key(Int(10))),
+            (int_key(Int(10)), int_key(Int(15))),
+        ]);
+        let trial = intersect_ranges(splits.clone(), &int_key(Int(20)), &int_key(InfMax)).unwrap();
+        assert_eq!(trial, vec![(int_key(Int(20)), int_key(InfMax))]);
+        let trial = intersect_ranges(splits.clone(), &int_key(Int(15)), &int_key(Int(5))).unwrap();
+        assert!(trial.is_empty());
+        let trial = intersect_ranges(splits, &int_key(Str("a".to_string())), &int_key(InfMax));
+        assert!(trial.is_err());
+    }
+
+    // Splits at 10 and 20. GetRange from 10 fails, others return the start row.
+    async fn serve() -> Client {
+        let make_svc = hyper::service::make_service_fn(|_| async {
+            Ok::<_, Infallible>(hyper::service::service_fn(|req: hyper::Request<hyper::Body>| async move {
+                let path = req.uri().path().to_string();
+                let body = hyper::body::to_bytes(req.into_body()).await.unwrap();
+                let (status, body) = match path.as_str() {
+                    "/ComputeSplitPointsBySize" => {
+                        let resp = pb::ComputeSplitPointsBySizeResponse{
+                            schema: vec![pb::PrimaryKeySchema{
+                                name: "pk".to_string(),
+                                type_pb: pb::PrimaryKeyType::INTEGER,
+                                option: None,
+                            }],
+                            split_points: vec![row(10).row_key.to_pbuf(), row(20).row_key.to_pbuf()],
+                            ..Default::default()
+                        };
+                        (200, types::serialize_message(&resp))
+                    }
+                    "/GetRange" => {
+                        let req: pb::GetRangeRequest = types::deserialize_message(&body).unwrap();
+                        let start = types::ExtendedRowKey::from_pbuf(req.inclusive_start_primary_key.into())
+                            .unwrap()
+                            .into_iter()
+                            .next()
+                            .unwrap()
+                            .value;
+                        match start {
+                            types::ExtendedRowKeyValue::Int(10) => {
+                                let err = pb::Error{
+                                    code: "OTSParameterInvalid".to_string(),
+                                    message: Some("broken split".to_string()),
+                                };
+                                (400, types::serialize_message(&err))
+                            }
+                            x => {
+                                let x = match x {
+                                    types::ExtendedRowKeyValue::Int(x) => x,
+                                    _ => 0,
+                                };
+                                let resp = pb::GetRangeResponse{
+                                    rows: vec![row(x)].to_pbuf(),
+                                    ..Default::default()
+                                };
+                                (200, types::serialize_message(&resp))
+                            }
+                        }
+                    }
+                    _ => (404, vec![]),
+                };
+                let resp = hyper::Response::builder()
+                    .status(status)
+                    .body(hyper::Body::from(body))
+                    .unwrap();
+                Ok::<_, Infallible>(resp)
+            }))
+        });
+        let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_svc);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+        let ep = crate::Endpoint::new(format!("http://{}", addr), "local").unwrap();
+        let cred = crate::Credential::new("id", "secret").unwrap();
+        Client::new(ep, cred, ClientOptions::default()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn parallel_scan_ends_at_first_error() {
+        use types::ExtendedRowKeyValue::*;
+        let client = serve().await;
+        let req = types::GetRangeRequest::new("t", int_key(InfMin), int_key(InfMax)).unwrap();
+        let trial: Vec<_> = client.parallel_scan(req, 1, 1).collect().await;
+        assert_eq!(trial.len(), 2);
+        assert_eq!(trial[0].as_ref().unwrap().row_key, row(0).row_key);
+        match &trial[1] {
+            Err(Error{code: ErrorCode::OTSParameterInvalid, ..}) => {}
+            x => panic!("unexpected {:?}", x),
+        }
+    }
+
+    #[tokio::test]
+    async fn parallel_scan_within_range() {
+        use types::ExtendedRowKeyValue::*;
+        let client = serve().await;
+        let req = types::GetRangeRequest::new("t", int_key(Int(15)), int_key(InfMax)).unwrap();
+        let mut trial: Vec<_> = client.parallel_scan(req, 1, 2)
+            .map(|x| {
+                x.unwrap().row_key
+            })
+            .collect()
+            .await;
+        // in no particular order
+        trial.sort_by_key(|x| match x.0[0].value {
+            types::RowKeyValue::Int(x) => x,
+            _ => unreachable!(),
+        });
+        assert_eq!(trial, vec![row(15).row_key, row(20).row_key]);
+
+        let mut req = types::GetRangeRequest::new("t", int_key(InfMin), int_key(InfMax)).unwrap();
+        req.direction = types::Direction::Backward;
+        let trial: Vec<_> = client.parallel_scan(req, 1, 1).collect().await;
+        assert_eq!(trial.len(), 1);
+        assert!(trial[0].is_err());
+    }
+}
@@ -1,109 +1,122 @@
 use bytes::Bytes;
 use chrono::prelude::*;
-use crate::{Endpoint, Credential, ClientOptions, Error, ErrorCode, types};
+use crate::{Endpoint, Credential, ClientOptions, HttpVersion, OnConcurrencyExhausted, Timeouts, Error, ErrorCode, types};
 use crypto::digest::Digest;
 use crypto::mac::Mac;
 use log::*;
 use std::collections::BTreeMap;
 use std::convert::{TryFrom, TryInto};
-use std::sync::atomic::{AtomicI64, Ordering};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
 use tokio::stream::StreamExt;
-use tokio::sync::{mpsc, oneshot};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
-#[derive(Clone)]
 pub(crate) struct ClientImpl {
     endpoint: Endpoint,
     credential: Credential,
     opts: ClientOptions,
-    http_clients: hyper::Client<hyper::client::HttpConnector<hyper::client::connect::dns::GaiResolver>, hyper::Body>,
+    consumed: ConsumedCounter,
+    concurrency: Concurrency,
+    http_clients: hyper::Client<TimeoutConnector<Connector>, hyper::Body>,
 }
 
+#[cfg(feature = "rustls")]
+type Connector = hyper_rustls::HttpsConnector<hyper::client::HttpConnector>;
+#[cfg(not(feature = "rustls"))]
+type Connector = hyper::client::HttpConnector;
+
 impl ClientImpl {
     pub(crate) fn new(
         endpoint: Endpoint,
         credential: Credential,
         opts: ClientOptions,
-    ) -> mpsc::Sender<Cmd> {
-        let (tx, rx) = mpsc::channel(1);
-        let client = ClientImpl{
+    ) -> Result<ClientImpl, Error> {
+        let connector = TimeoutConnector{
+            inner: new_connector(&endpoint, &opts)?,
+            timeout: opts.timeouts.connect,
+        };
+        let http2_only = opts.http_version == HttpVersion::Http2
+            && !endpoint.address.starts_with("https://"); // otherwise negotiated by ALPN
+        let http_clients = hyper::Client::builder()
+            .pool_max_idle_per_host(opts.pool_max_idle_per_host)
+            .pool_idle_timeout(opts.pool_idle_timeout)
+            .http2_only(http2_only)
+            .build(connector);
+        let concurrency = Concurrency::new(
+            opts.concurrency,
+            opts.on_concurrency_exhausted);
+        Ok(ClientImpl{
             endpoint,
             credential,
             opts,
-            http_clients: hyper::Client::new(),
-        };
-        tokio::spawn(client.run(rx));
-        tx
+            consumed: ConsumedCounter::default(),
+            concurrency,
+            http_clients,
+        })
     }
 
-    async fn run(self, mut cmd_recv: mpsc::Receiver<Cmd>) {
-        let mut concurrency = Concurrency::new(self.opts.concurrency);
-        while let Some(cmd) = cmd_recv.recv().await {
-            match cmd {
-                Cmd::ListTable(req, resp_tx) => {
-                    self.async_issue(req, resp_tx, &mut concurrency);
-                }
-                Cmd::CreateTable(req, resp_tx) => {
-                    self.async_issue(req, resp_tx, &mut concurrency);
-                }
-                Cmd::DeleteTable(req, resp_tx) => {
-                    self.async_issue(req, resp_tx, &mut concurrency);
-                }
-                Cmd::PutRow(req, resp_tx) => {
-                    self.async_issue(req, resp_tx, &mut concurrency);
-                }
-            }
-        }
+    pub(crate) fn consumed_capacity(&self) -> types::ConsumedCapacity {
+        self.consumed.snapshot()
+    }
+
+    // Dropping the returned future cancels the request.
+    pub(crate) async fn call<Req, Resp>(
+        &self,
+        req: Req,
+        timeouts: Timeouts,
+    ) -> Result<Resp, Error>
+    where
+        Req: types::Request + Clone + Into<Bytes> + std::fmt::Debug,
+        Resp: types::Response + TryFrom<Vec<u8>, Error=Error> + std::fmt::Debug,
+    {
+        let work = within(timeouts.total, self.issue_with_retry(req, timeouts.attempt));
+        CONNECT_TIMEOUT.scope(timeouts.connect, work).await
     }
 
-    fn async_issue<Req, Resp>(
+    async fn issue_with_retry<Req, Resp>(
         &self,
         req: Req,
-        resp_tx: oneshot::Sender<Result<Resp, Error>>,
-        concurrency: &mut Concurrency,
-    ) -> ()
+        attempt_timeout: Option<Duration>,
+    ) -> Result<Resp, Error>
     where
-        Req: 'static + types::Request + Clone + Into<Bytes> + Send + Sync + std::fmt::Debug,
-        Resp: 'static + types::Response + TryFrom<Vec<u8>, Error=Error> + std::fmt::Debug + Send,
+        Req: types::Request + Clone + Into<Bytes> + std::fmt::Debug,
+        Resp: types::Response + TryFrom<Vec<u8>, Error=Error> + std::fmt::Debug,
     {
-        let atom = match concurrency.borrow() {
+        let _atom = match self.concurrency.borrow().await {
             Ok(x) => x,
-            Err(mut err) => {
+            Err(err) => {
                 info!("Too many concurrent requests.");
-                err.message = "too many concurrent requests.".to_string();
-                resp_tx.send(Err(err)).unwrap();
-                return;
+                return Err(err);
             }
         };
-        let client = self.clone();
-        tokio::spawn(async move {
-            let _atom = atom;
-            let mut retry = client.opts.retry_strategy.clone();
-            let resp = loop {
-                let resp = client.issue(req.clone()).await;
-                match resp {
-                    Ok(_) => {
-                        break resp;
-                    }
-                    Err(err) => {
-                        match retry.next_pause(req.action(), &err) {
-                            None => {
-                                break Err(err);
-                            }
-                            Some(dur) => {
-                                info!("Retriable error occurs.\
-                                    \terror={:?}\
-                                    \tdelay={:?}",
-                                    err,
-                                    dur);
-                                tokio::time::delay_for(dur).await;
-                            }
+        let mut retry = self.opts.retry_strategy.clone();
+        loop {
+            let resp = within(attempt_timeout, self.issue(req.clone())).await;
+            match resp {
+                Ok(_) => {
+                    return resp;
+                }
+                Err(err) => {
+                    match retry.next_pause(req.action(), &err) {
+                        None => {
+                            return Err(err);
+                        }
+                        Some(dur) => {
+                            info!("Retriable error occurs.\
+                                \terror={:?}\
+                                \tdelay={:?}",
+                                err,
+                                dur);
+                            tokio::time::delay_for(dur).await;
                         }
                     }
                 }
-            };
-            resp_tx.send(resp).unwrap()
-        });
+            }
+        }
     }
 
     async fn issue<Req, Resp>(
@@ -176,12 +189,8 @@ impl ClientImpl {
         let body: Bytes = req.into();
         debug!("body: {:?}", body);
         self.build_headers(&path, req_builder.headers_mut().unwrap(), &body)?;
-        let (mut sender, bd) = hyper::Body::channel();
-        let req = req_builder.body(bd)?;
-        let body = sender.send_data(body);
-        let resp = self.http_clients.request(req);
-        body.await?;
-        let resp = resp.await?;
+        let req = req_builder.body(hyper::Body::from(body))?;
+        let resp = self.http_clients.request(req).await?;
         Ok(resp)
     }
 
@@ -258,6 +267,7 @@ impl ClientImpl {
             StatusKind::Ok => {
                 let mut resp: Resp = body.try_into()?;
                 resp.reset_base(server_timestamp, req_id);
+                self.consumed.add(resp.consumed());
                 Ok(resp)
             }
             StatusKind::ErrorFromService => Err(body.as_slice().try_into()?),
@@ -281,26 +291,6 @@ impl ClientImpl {
     }
 }
 
-#[derive(Debug)]
-pub(crate) enum Cmd {
-    ListTable(
-        types::ListTableRequest,
-        oneshot::Sender<Result<types::ListTableResponse, Error>>,
-    ),
-    CreateTable(
-        types::CreateTableRequest,
-        oneshot::Sender<Result<types::CreateTableResponse, Error>>,
-    ),
-    DeleteTable(
-        types::DeleteTableRequest,
-        oneshot::Sender<Result<types::DeleteTableResponse, Error>>,
-    ),
-    PutRow(
-        types::PutRowRequest,
-        oneshot::Sender<Result<types::PutRowResponse, Error>>,
-    ),
-}
-
 const HEADER_NAME_API_VERSION: &str = "x-ots-apiversion";
 const HEADER_VALUE_API_VERSION: &str = "2015-12-31";
 const HEADER_NAME_ACCESS_KEY_ID: &str = "x-ots-accesskeyid";
@@ -320,6 +310,114 @@ enum StatusKind {
     ErrorFromMiddle,
 }
 
+fn new_http_connector(opts: &ClientOptions) -> hyper::client::HttpConnector {
+    let mut http = hyper::client::HttpConnector::new();
+    http.set_keepalive(opts.tcp_keepalive);
+    http.set_nodelay(opts.tcp_nodelay);
+    http.set_local_address(opts.local_address);
+    http
+}
+
+#[cfg(feature = "rustls")]
+fn new_connector(_endpoint: &Endpoint, opts: &ClientOptions) -> Result<Connector, Error> {
+    let mut config = rustls::ClientConfig::new();
+    config.alpn_protocols = match opts.http_version {
+        HttpVersion::Http1 => vec![b"http/1.1".to_vec()],
+        HttpVersion::Http2 => vec![b"h2".to_vec(), b"http/1.1".to_vec()],
+    };
+    match &opts.tls_ca_certs {
+        None => {
+            config.root_store.add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+        }
+        Some(ca_certs) => {
+            let mut reader = std::io::BufReader::new(ca_certs.as_slice());
+            match config.root_store.add_pem_file(&mut reader) {
+                Ok((valid, _)) if valid > 0 => {}
+                _ => {
+                    return Err(Error{
+                        code: ErrorCode::ClientUnknown,
+                        message: "No valid CA certificate in the PEM bundle.".to_string(),
+                    });
+                }
+            }
+        }
+    }
+    let mut http = new_http_connector(opts);
+    http.enforce_http(false);
+    Ok(Connector::from((http, config)))
+}
+
+#[cfg(not(feature = "rustls"))]
+fn new_connector(endpoint: &Endpoint, opts: &ClientOptions) -> Result<Connector, Error> {
+    if endpoint.address.starts_with("https://") {
+        return Err(Error{
+            code: ErrorCode::ClientUnknown,
+            message: "HTTPS endpoints require the \"rustls\" feature.".to_string(),
+        });
+    }
+    Ok(new_http_connector(opts))
+}
+
+tokio::task_local! {
+    // overrides the connect timeout of TimeoutConnector for connections made by a request
+    static CONNECT_TIMEOUT: Option<Duration>;
+}
+
+#[derive(Clone)]
+struct TimeoutConnector<C> {
+    inner: C,
+    timeout: Option<Duration>,
+}
+
+impl<C> hyper::service::Service<http::Uri> for TimeoutConnector<C>
+where
+    C: hyper::service::Service<http::Uri>,
+    C::Future: Send + 'static,
+    C::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = C::Response;
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+    type Future = Pin<Box<dyn Future<Output=Result<C::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, uri: http::Uri) -> Self::Future {
+        let timeout = CONNECT_TIMEOUT
+            .try_with(|x| *x)
+            .unwrap_or(self.timeout);
+        let conn = self.inner.call(uri);
+        Box::pin(async move {
+            match timeout {
+                None => conn.await.map_err(Into::into),
+                Some(dur) => match tokio::time::timeout(dur, conn).await {
+                    Ok(x) => x.map_err(Into::into),
+                    Err(_) => Err(Box::new(std::io::Error::new(
+                        std::io::ErrorKind::TimedOut,
+                        format!("connect timed out after {:?}", dur))) as Self::Error),
+                },
+            }
+        })
+    }
+}
+
+async fn within<F, T>(timeout: Option<Duration>, fut: F) -> Result<T, Error>
+where
+    F: Future<Output=Result<T, Error>>,
+{
+    match timeout {
+        None => fut.await,
+        Some(dur) => match tokio::time::timeout(dur, fut).await {
+            Ok(x) => x,
+            Err(_) => Err(Error{
+                code: ErrorCode::OperationTimeout,
+                message: format!("timed out after {:?}.", dur),
+            }),
+        },
+    }
+}
+
 async fn collect_body(mut resp_body: hyper::Body) -> Result<Vec<u8>, Error> {
     let mut body: Vec<u8> = vec![];
     while let Some(piece) = resp_body.next().await {
@@ -452,33 +550,224 @@ fn content_md5(body: &[u8]) -> Result<String, Error> {
     Ok(digest)
 }
 
-struct Concurrency(Arc<AtomicI64>);
+#[derive(Default)]
+struct ConsumedCounter {
+    read: AtomicI64,
+    write: AtomicI64,
+}
+
+impl ConsumedCounter {
+    fn add(&self, x: types::ConsumedCapacity) {
+        self.read.fetch_add(x.read, Ordering::Relaxed);
+        self.write.fetch_add(x.write, Ordering::Relaxed);
+    }
 
-struct ConcurrencyBorrower(Arc<AtomicI64>);
+    fn snapshot(&self) -> types::ConsumedCapacity {
+        types::ConsumedCapacity{
+            read: self.read.load(Ordering::Relaxed),
+            write: self.write.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Concurrency {
+    slots: Arc<Semaphore>,
+    on_exhausted: OnConcurrencyExhausted,
+    wait_queue: WaitQueue,
+}
 
 impl Concurrency {
-    fn new(slots: i64) -> Concurrency {
-        Concurrency(Arc::new(AtomicI64::new(slots)))
+    fn new(slots: i64, on_exhausted: OnConcurrencyExhausted) -> Concurrency {
+        let max_waiting = match on_exhausted {
+            OnConcurrencyExhausted::FailFast => 0,
+            OnConcurrencyExhausted::Wait{max_waiting, ..} => max_waiting,
+        };
+        Concurrency{
+            slots: Arc::new(Semaphore::new(std::cmp::max(slots, 0) as usize)),
+            on_exhausted,
+            wait_queue: WaitQueue::new(max_waiting),
+        }
+    }
+
+    async fn borrow(&self) -> Result<OwnedSemaphorePermit, Error> {
+        if let Ok(x) = self.slots.clone().try_acquire_owned() {
+            return Ok(x);
+        }
+        let timeout = match self.on_exhausted {
+            OnConcurrencyExhausted::FailFast => {
+                return Err(Error{
+                    code: ErrorCode::NoAvailableConnection,
+                    message: "too many concurrent requests.".to_string(),
+                });
+            }
+            OnConcurrencyExhausted::Wait{timeout, ..} => timeout,
+        };
+        let _place = self.wait_queue.enter()?;
+        let permit = self.slots.clone().acquire_owned();
+        match timeout {
+            None => Ok(permit.await),
+            Some(dur) => match tokio::time::timeout(dur, permit).await {
+                Ok(x) => Ok(x),
+                Err(_) => Err(Error{
+                    code: ErrorCode::NoAvailableConnection,
+                    message: format!("no available connection after waiting {:?}.", dur),
+                }),
+            },
+        }
     }
+}
+
+#[derive(Clone)]
+struct WaitQueue(Arc<AtomicUsize>);
 
-    fn borrow(&mut self) -> Result<ConcurrencyBorrower, Error> {
-        let c = self.0.fetch_sub(1, Ordering::Acquire);
-        debug!("concurrency before acquiring: {}", c);
-        if c <= 0 {
-            self.0.fetch_add(1, Ordering::Release);
-            let err = Error{
+struct WaitQueuePlace(Arc<AtomicUsize>);
+
+impl WaitQueue {
+    fn new(places: usize) -> WaitQueue {
+        WaitQueue(Arc::new(AtomicUsize::new(places)))
+    }
+
+    fn enter(&self) -> Result<WaitQueuePlace, Error> {
+        match self.0.fetch_update(Ordering::Acquire, Ordering::Relaxed, |x| x.checked_sub(1)) {
+            Ok(c) => {
+                debug!("wait-queue places before entering: {}", c);
+                Ok(WaitQueuePlace(self.0.clone()))
+            }
+            Err(_) => Err(Error{
                 code: ErrorCode::NoAvailableConnection,
-                message: String::new(),
-            };
-            return Err(err);
+                message: "too many requests waiting for connections.".to_string(),
+            }),
         }
-        Ok(ConcurrencyBorrower(self.0.clone()))
     }
 }
 
-impl Drop for ConcurrencyBorrower {
+impl Drop for WaitQueuePlace {
     fn drop(&mut self) {
         let c = self.0.fetch_add(1, Ordering::Release);
-        debug!("concurrency after releasing: {}", c + 1);
+        debug!("wait-queue places after leaving: {}", c + 1);
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+    use crate::{Action, DeadlineRetryStrategy, RetryStrategy};
+
+    #[derive(Clone)]
+    struct PendingConnector;
+
+    impl hyper::service::Service<http::Uri> for PendingConnector {
+        type Response = tokio::net::TcpStream;
+        type Error = std::io::Error;
+        type Future = std::future::Pending<Result<tokio::net::TcpStream, std::io::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: http::Uri) -> Self::Future {
+            std::future::pending()
+        }
+    }
+
+    #[tokio::test]
+    async fn connect_timeout() {
+        let connect = |timeout| async move {
+            let connector = TimeoutConnector{
+                inner: PendingConnector,
+                timeout,
+            };
+            let client: hyper::Client<_, hyper::Body> = hyper::Client::builder().build(connector);
+            let err = client.get(http::Uri::from_static("http://127.0.0.1/")).await.unwrap_err();
+            Error::from(err)
+        };
+        let err = connect(Some(Duration::from_millis(10))).await;
+        match &err {
+            Error{code: ErrorCode::CouldntConnect, ..} => {}
+            x => panic!("unexpected {:?}", x),
+        }
+        // nothing is sent, so that even writes are retried
+        let mut retry = DeadlineRetryStrategy::new(Duration::from_secs(1));
+        assert!(retry.next_pause(Action::PutRow, &err).is_some());
+        // overridden per request
+        let trial = CONNECT_TIMEOUT.scope(Some(Duration::from_millis(10)), connect(None)).await;
+        match trial {
+            Error{code: ErrorCode::CouldntConnect, ..} => {}
+            x => panic!("unexpected {:?}", x),
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrency_fails_fast() {
+        let concurrency = Concurrency::new(1, OnConcurrencyExhausted::FailFast);
+        let atom = concurrency.borrow().await.unwrap();
+        match concurrency.borrow().await {
+            Err(Error{code: ErrorCode::NoAvailableConnection, ..}) => {}
+            x => panic!("unexpected {:?}", x.map(|_| ())),
+        }
+        drop(atom);
+        assert!(concurrency.borrow().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn concurrency_waits_in_bounded_queue() {
+        let concurrency = Concurrency::new(1, OnConcurrencyExhausted::Wait{
+            max_waiting: 1,
+            timeout: None,
+        });
+        let atom = concurrency.borrow().await.unwrap();
+        let waiter = {
+            let concurrency = concurrency.clone();
+            tokio::spawn(async move {
+                concurrency.borrow().await.map(|_| ())
+            })
+        };
+        tokio::time::delay_for(Duration::from_millis(10)).await;
+        match concurrency.borrow().await {
+            Err(Error{code: ErrorCode::NoAvailableConnection, ..}) => {}
+            x => panic!("unexpected {:?}", x.map(|_| ())),
+        }
+        drop(atom);
+        assert!(waiter.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn concurrency_waits_in_unbounded_queue() {
+        let concurrency = Concurrency::new(1, OnConcurrencyExhausted::Wait{
+            max_waiting: usize::MAX,
+            timeout: None,
+        });
+        let atom = concurrency.borrow().await.unwrap();
+        let waiters: Vec<_> = (0..3)
+            .map(|_| {
+                let concurrency = concurrency.clone();
+                tokio::spawn(async move {
+                    concurrency.borrow().await.map(|_| ())
+                })
+            })
+            .collect();
+        tokio::time::delay_for(Duration::from_millis(10)).await;
+        assert_eq!(concurrency.wait_queue.0.load(Ordering::Acquire), usize::MAX - 3);
+        drop(atom);
+        for x in waiters.into_iter() {
+            assert!(x.await.unwrap().is_ok());
+        }
+        assert_eq!(concurrency.wait_queue.0.load(Ordering::Acquire), usize::MAX);
+    }
+
+    #[tokio::test]
+    async fn concurrency_wait_times_out() {
+        let concurrency = Concurrency::new(1, OnConcurrencyExhausted::Wait{
+            max_waiting: 1,
+            timeout: Some(Duration::from_millis(10)),
+        });
+        let _atom = concurrency.borrow().await.unwrap();
+        match concurrency.borrow().await {
+            Err(Error{code: ErrorCode::NoAvailableConnection, ..}) => {}
+            x => panic!("unexpected {:?}", x.map(|_| ())),
+        }
+        // the timed-out request leaves the queue
+        assert_eq!(concurrency.wait_queue.0.load(Ordering::Acquire), 1);
     }
 }
@@ -1,16 +1,70 @@
 use crate::{RetryStrategy, DeadlineRetryStrategy};
+use std::net::IpAddr;
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct ClientOptions {
     pub concurrency: i64,
+    pub on_concurrency_exhausted: OnConcurrencyExhausted,
+    pub timeouts: Timeouts, // can be overridden per request by Client::with_timeouts()
     pub retry_strategy: Box<dyn RetryStrategy + Send + Sync>,
+    pub pool_max_idle_per_host: usize, // 0 disables reusing connections
+    pub pool_idle_timeout: Option<Duration>, // idle connections are closed after that, None to keep them forever
+    pub tcp_keepalive: Option<Duration>, // SO_KEEPALIVE, None to disable
+    pub tcp_nodelay: bool,
+    pub http_version: HttpVersion,
+    pub local_address: Option<IpAddr>, // to bind connections to
+    #[cfg(feature = "rustls")]
+    pub tls_ca_certs: Option<Vec<u8>>, // PEM, instead of the bundled webpki roots
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OnConcurrencyExhausted {
+    FailFast,
+    Wait{
+        max_waiting: usize, // requests beyond fail fast
+        timeout: Option<Duration>, // for a request to wait in the queue
+    },
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Timeouts {
+    pub connect: Option<Duration>, // to establish a connection, TLS handshake included
+    pub attempt: Option<Duration>, // for a single try, from sending the request to receiving the whole response
+    pub total: Option<Duration>, // for a request, including waiting for concurrency and all retries
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum HttpVersion {
+    Http1,
+    Http2, // prior knowledge over plain TCP, or preferred in ALPN over TLS
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self{
+            connect: Some(Duration::from_secs(10)),
+            attempt: Some(Duration::from_secs(30)),
+            total: None, // bounded by the retry strategy
+        }
+    }
 }
 
 impl Default for ClientOptions {
     fn default() -> Self {
         Self{
             concurrency: 1000,
-            retry_strategy: Box::new(DeadlineRetryStrategy::new(std::time::Duration::from_secs(300))),
+            on_concurrency_exhausted: OnConcurrencyExhausted::FailFast,
+            timeouts: Timeouts::default(),
+            retry_strategy: Box::new(DeadlineRetryStrategy::new(Duration::from_secs(300))),
+            pool_max_idle_per_host: usize::MAX,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            tcp_keepalive: None,
+            tcp_nodelay: false,
+            http_version: HttpVersion::Http1,
+            local_address: None,
+            #[cfg(feature = "rustls")]
+            tls_ca_certs: None,
         }
     }
 }
@@ -19,6 +73,14 @@ impl std::fmt::Debug for ClientOptions {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ClientOptions")
             .field("concurrency", &self.concurrency)
+            .field("on_concurrency_exhausted", &self.on_concurrency_exhausted)
+            .field("timeouts", &self.timeouts)
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .field("tcp_keepalive", &self.tcp_keepalive)
+            .field("tcp_nodelay", &self.tcp_nodelay)
+            .field("http_version", &self.http_version)
+            .field("local_address", &self.local_address)
             .finish()
     }
 }
@@ -50,6 +50,7 @@ impl From<hyper::Error> for Error {
             x if x.is_user() => ErrorCode::CorruptedResponse,
             x if x.is_canceled() => ErrorCode::WriteRequestFail,
             x if x.is_closed() => ErrorCode::WriteRequestFail,
+            x if x.is_connect() && is_tls_error(x) => ErrorCode::SslHandshakeFail,
             x if x.is_connect() => ErrorCode::CouldntConnect,
             x if x.is_incomplete_message() => ErrorCode::CorruptedResponse,
             x if x.is_body_write_aborted() => ErrorCode::WriteRequestFail,
@@ -63,6 +64,32 @@ impl From<hyper::Error> for Error {
     }
 }
 
+#[cfg(feature = "rustls")]
+fn is_tls_error(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut cur = Some(err);
+    while let Some(x) = cur {
+        if x.is::<rustls::TLSError>() {
+            return true;
+        }
+        cur = next_error(x);
+    }
+    false
+}
+
+#[cfg(feature = "rustls")]
+fn next_error<'a>(err: &'a (dyn std::error::Error + 'static)) -> Option<&'a (dyn std::error::Error + 'static)> {
+    // source() of an io error skips the wrapped one, and tokio-rustls wraps TLS errors into io errors
+    match err.downcast_ref::<std::io::Error>().and_then(|x| x.get_ref()) {
+        Some(inner) => Some(inner),
+        None => err.source(),
+    }
+}
+
+#[cfg(not(feature = "rustls"))]
+fn is_tls_error(_: &(dyn std::error::Error + 'static)) -> bool {
+    false
+}
+
 impl From<quick_protobuf::errors::Error> for Error {
     fn from(qe: quick_protobuf::errors::Error) -> Error {
         Error{
@@ -156,3 +183,20 @@ impl From<chrono::format::ParseError> for Error {
         }
     }
 }
+
+#[cfg(all(test, feature = "rustls"))]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn tls_errors_are_recognized() {
+        let err = std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            rustls::TLSError::NoCertificatesPresented);
+        assert!(is_tls_error(&err));
+        let err = std::io::Error::other(err); // as by hyper's connector
+        assert!(is_tls_error(&err));
+        let err = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused");
+        assert!(!is_tls_error(&err));
+    }
+}
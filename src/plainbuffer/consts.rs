@@ -29,6 +29,13 @@ pub enum Tag {
     CellChecksum = 0x0A,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CellType {
+    DeleteAllVersions = 0x1,
+    DeleteOneVersion = 0x3,
+    Increment = 0x4,
+}
+
 impl Default for VariantType {
     fn default() -> Self {
         VariantType::Null
@@ -87,4 +94,20 @@ impl TryFrom<u8> for Tag {
     }
 }
 
+impl TryFrom<u8> for CellType {
+    type Error = crate::Error;
+
+    fn try_from(x: u8) -> Result<Self, Self::Error> {
+        match x {
+            0x1 => Ok(CellType::DeleteAllVersions),
+            0x3 => Ok(CellType::DeleteOneVersion),
+            0x4 => Ok(CellType::Increment),
+            _ => Err(Error{
+                code: ErrorCode::CorruptedResponse,
+                message: "".to_string(),
+            })
+        }
+    }
+}
+
 pub const HEADER: u32 = 0x75;
@@ -10,6 +10,11 @@ mod consts;
 pub(in crate::plainbuffer) use consts::*;
 mod serde;
 pub(in crate::plainbuffer) use serde::*;
+mod sqlvariant;
+pub(crate) use sqlvariant::*;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct RowDeletion(pub crate::types::RowKey);
 
 pub(crate) trait PbufSerde: Sized {
     fn to_pbuf(&self) -> Vec<u8>;
@@ -33,6 +38,80 @@ impl PbufSerde for crate::types::Row {
     }
 }
 
+impl PbufSerde for crate::types::RowChange {
+    fn to_pbuf(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = vec![];
+        consts::HEADER.serialize(&mut buf);
+        self.serialize(&mut buf);
+        buf
+    }
+
+    fn from_pbuf(mut buf: Bytes) -> Result<Self, Error> {
+        let header = u32::deserialize(&mut buf)?;
+        if header != consts::HEADER {
+            return serde::issue_error();
+        }
+        crate::types::RowChange::deserialize(&mut buf)
+    }
+}
+
+impl PbufSerde for RowDeletion {
+    fn to_pbuf(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = vec![];
+        consts::HEADER.serialize(&mut buf);
+        self.serialize(&mut buf);
+        buf
+    }
+
+    fn from_pbuf(mut buf: Bytes) -> Result<Self, Error> {
+        let header = u32::deserialize(&mut buf)?;
+        if header != consts::HEADER {
+            return serde::issue_error();
+        }
+        RowDeletion::deserialize(&mut buf)
+    }
+}
+
+impl PbufSerde for crate::types::RowKey {
+    fn to_pbuf(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = vec![];
+        consts::HEADER.serialize(&mut buf);
+        let mut checksum = 0u8;
+        self.serialize_crc8(&mut buf, &mut checksum);
+        serde::serialize_row_tail(&mut buf, checksum, false);
+        buf
+    }
+
+    fn from_pbuf(buf: Bytes) -> Result<Self, Error> {
+        let row = crate::types::Row::from_pbuf(buf)?;
+        Ok(row.row_key)
+    }
+}
+
+impl PbufSerde for crate::types::ExtendedRowKey {
+    fn to_pbuf(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = vec![];
+        consts::HEADER.serialize(&mut buf);
+        let mut checksum = 0u8;
+        self.serialize_crc8(&mut buf, &mut checksum);
+        serde::serialize_row_tail(&mut buf, checksum, false);
+        buf
+    }
+
+    fn from_pbuf(mut buf: Bytes) -> Result<Self, Error> {
+        let header = u32::deserialize(&mut buf)?;
+        if header != consts::HEADER {
+            return serde::issue_error();
+        }
+        let mut checksum = 0u8;
+        let res = crate::types::ExtendedRowKey::deserialize_crc8(&mut buf, &mut checksum)?;
+        if serde::deserialize_row_tail(&mut buf, checksum)? {
+            return serde::issue_error();
+        }
+        Ok(res)
+    }
+}
+
 impl PbufSerde for Vec<crate::types::Row> {
     fn to_pbuf(&self) -> Vec<u8> {
         let mut buf: Vec<u8> = vec![];
@@ -337,10 +337,9 @@ impl SerdeWithCrc8 for ExtendedRowKeyValue {
             super::VariantType::AutoIncrement => {
                 Ok(ExtendedRowKeyValue::AutoIncr)
             }
-            _ => unimplemented!()
+            _ => issue_error(),
         };
         if exp_payload_len != real_payload_len {
-            println!("here: {} {}", exp_payload_len, real_payload_len);
             return issue_error();
         }
         res
@@ -436,11 +435,8 @@ impl SerdeWithCrc8 for ExtendedRowKeyColumn {
         }
         let mut real_chksum = 0u8;
         let name = Name::deserialize_crc8(inp, &mut real_chksum)?;
-        println!("name: {:?}", name);
         let value = ExtendedRowKeyValue::deserialize_crc8(inp, &mut real_chksum)?;
-        println!("value: {:?}", value);
         deser_check_checksum(inp, real_chksum)?;
-        println!("real checksum: {:x}", real_chksum);
         super::crc8_u8(checksum, real_chksum);
         Ok(ExtendedRowKeyColumn{
             name,
@@ -454,7 +450,7 @@ impl SerdeWithCrc8 for AttrValue {
         super::Tag::CellValue.serialize(out);
         match self {
             AttrValue::Str(x) => {
-                ((x.len() + 1) as u32).serialize(out);
+                ((x.len() + std::mem::size_of::<u32>() + 1) as u32).serialize(out);
                 super::VariantType::String.serialize_crc8(out, checksum);
                 x.serialize_crc8(out, checksum);
             }
@@ -464,7 +460,7 @@ impl SerdeWithCrc8 for AttrValue {
                 x.serialize_crc8(out, checksum);
             }
             AttrValue::Blob(x) => {
-                ((x.len() + 1) as u32).serialize(out);
+                ((x.len() + std::mem::size_of::<u32>() + 1) as u32).serialize(out);
                 super::VariantType::Blob.serialize_crc8(out, checksum);
                 x.serialize_crc8(out, checksum);
             }
@@ -486,6 +482,10 @@ impl SerdeWithCrc8 for AttrValue {
                 out.put_slice(xs.as_ref());
                 super::crc8_blob(checksum, &xs);
             }
+            AttrValue::Null => {
+                1u32.serialize(out);
+                super::VariantType::Null.serialize_crc8(out, checksum);
+            }
         }
     }
 
@@ -497,33 +497,46 @@ impl SerdeWithCrc8 for AttrValue {
         if expect_cellvalue != super::Tag::CellValue {
             return issue_error();
         }
-        let _ = u32::deserialize(inp)?;
+        let exp_payload_len = usize::try_from(u32::deserialize(inp)?).unwrap();
         let vt = super::VariantType::deserialize_crc8(inp, checksum)?;
-        match vt {
+        let mut real_payload_len = 1usize;
+        let res = match vt {
             super::VariantType::Integer => {
                 let x = i64::deserialize_crc8(inp, checksum)?;
-                Ok(AttrValue::Int(x))
+                real_payload_len += std::mem::size_of_val(&x);
+                AttrValue::Int(x)
             }
             super::VariantType::String => {
                 let s = String::deserialize_crc8(inp, checksum)?;
-                Ok(AttrValue::Str(s))
+                real_payload_len += s.len();
+                real_payload_len += std::mem::size_of::<u32>();
+                AttrValue::Str(s)
             }
             super::VariantType::Blob => {
                 let b = Bytes::deserialize_crc8(inp, checksum)?;
-                Ok(AttrValue::Blob(b))
+                real_payload_len += b.len();
+                real_payload_len += std::mem::size_of::<u32>();
+                AttrValue::Blob(b)
             }
             super::VariantType::Boolean => {
                 let x = u8::deserialize(inp)?;
                 super::crc8_u8(checksum, x);
-                Ok(AttrValue::Bool(x > 0))
+                real_payload_len += std::mem::size_of_val(&x);
+                AttrValue::Bool(x > 0)
             }
             super::VariantType::Double => {
                 let x = u64::deserialize(inp)?;
                 super::crc8_u64(checksum, x);
-                Ok(AttrValue::Float(f64::from_le_bytes(x.to_le_bytes())))
+                real_payload_len += std::mem::size_of_val(&x);
+                AttrValue::Float(f64::from_le_bytes(x.to_le_bytes()))
             }
-            _ => unimplemented!()
+            super::VariantType::Null => AttrValue::Null,
+            _ => return issue_error(),
+        };
+        if exp_payload_len != real_payload_len {
+            return issue_error();
         }
+        Ok(res)
     }
 }
 
@@ -570,6 +583,106 @@ impl SerdeWithCrc8 for Attribute {
     }
 }
 
+impl Serde for super::CellType {
+    fn serialize(&self, out: &mut dyn BufMut) {
+        (*self as u8).serialize(out)
+    }
+
+    fn deserialize(inp: &mut dyn Buf) -> Result<super::CellType, Error> {
+        let x = u8::deserialize(inp)?;
+        let res = super::CellType::try_from(x)?;
+        Ok(res)
+    }
+}
+
+impl SerdeWithCrc8 for CellChange {
+    fn serialize_crc8(&self, out: &mut dyn BufMut, checksum: &mut u8) {
+        let (name, value, cell_type, tm) = match self {
+            CellChange::Put(attr) => {
+                attr.serialize_crc8(out, checksum);
+                return;
+            }
+            CellChange::DeleteOneVersion(name, tm) => {
+                (name, None, super::CellType::DeleteOneVersion, Some(tm))
+            }
+            CellChange::DeleteAllVersions(name) => {
+                (name, None, super::CellType::DeleteAllVersions, None)
+            }
+            CellChange::Increment(name, delta) => {
+                (name, Some(AttrValue::Int(*delta)), super::CellType::Increment, None)
+            }
+        };
+        super::Tag::Cell.serialize(out);
+        let mut cell_chksum = 0u8;
+        name.serialize_crc8(out, &mut cell_chksum);
+        if let Some(value) = value {
+            value.serialize_crc8(out, &mut cell_chksum);
+        }
+        super::Tag::CellType.serialize(out);
+        cell_type.serialize(out);
+        if let Some(tm) = tm {
+            super::Tag::CellTimestamp.serialize(out);
+            tm.to_millis().serialize_crc8(out, &mut cell_chksum);
+        }
+        // cell type is covered by checksum after timestamp
+        super::crc8_u8(&mut cell_chksum, cell_type as u8);
+        super::Tag::CellChecksum.serialize(out);
+        cell_chksum.serialize(out);
+        super::crc8_u8(checksum, cell_chksum);
+    }
+
+    fn deserialize_crc8(
+        inp: &mut dyn Buf,
+        checksum: &mut u8,
+    ) -> Result<Self, Error> {
+        if super::Tag::deserialize(inp)? != super::Tag::Cell {
+            return issue_error();
+        }
+        let mut cell_chksum = 0u8;
+        let name = Name::deserialize_crc8(inp, &mut cell_chksum)?;
+        let value = if peek_and_expect(inp, super::Tag::CellValue) {
+            Some(AttrValue::deserialize_crc8(inp, &mut cell_chksum)?)
+        } else {
+            None
+        };
+        let cell_type = if peek_and_expect(inp, super::Tag::CellType) {
+            super::Tag::deserialize(inp)?;
+            Some(super::CellType::deserialize(inp)?)
+        } else {
+            None
+        };
+        let tm = if peek_and_expect(inp, super::Tag::CellTimestamp) {
+            super::Tag::deserialize(inp)?;
+            let msecs = i64::deserialize_crc8(inp, &mut cell_chksum)?;
+            Some(DateTime::from_millis(msecs))
+        } else {
+            None
+        };
+        if let Some(cell_type) = cell_type {
+            super::crc8_u8(&mut cell_chksum, cell_type as u8);
+        }
+        deser_check_checksum(inp, cell_chksum)?;
+        super::crc8_u8(checksum, cell_chksum);
+        match (value, cell_type, tm) {
+            (Some(value), None, tm) => Ok(CellChange::Put(Attribute{
+                name,
+                value,
+                timestamp: AttrTimestamp::from(tm),
+            })),
+            (None, Some(super::CellType::DeleteOneVersion), Some(tm)) => {
+                Ok(CellChange::DeleteOneVersion(name, tm))
+            }
+            (None, Some(super::CellType::DeleteAllVersions), None) => {
+                Ok(CellChange::DeleteAllVersions(name))
+            }
+            (Some(AttrValue::Int(delta)), Some(super::CellType::Increment), None) => {
+                Ok(CellChange::Increment(name, delta))
+            }
+            _ => issue_error(),
+        }
+    }
+}
+
 impl SerdeWithCrc8 for RowKey {
     fn serialize_crc8(&self, out: &mut dyn BufMut, checksum: &mut u8) {
         super::Tag::RowKey.serialize(out);
@@ -607,7 +720,6 @@ impl SerdeWithCrc8 for ExtendedRowKey {
         let mut res = vec![];
         while peek_and_expect(inp, super::Tag::Cell) {
             let x = ExtendedRowKeyColumn::deserialize_crc8(inp, checksum)?;
-            println!("{:?}", x);
             res.push(x);
         }
         Ok(ExtendedRowKey::new(res))
@@ -624,9 +736,7 @@ impl Serde for Row {
                 x.serialize_crc8(out, &mut checksum);
             }
         }
-        super::crc8_u8(&mut checksum, 0); // placeholder for missing row-delete marker
-        super::Tag::RowChecksum.serialize(out);
-        checksum.serialize(out);
+        serialize_row_tail(out, checksum, false);
     }
 
     fn deserialize(inp: &mut dyn Buf) -> Result<Self, Error> {
@@ -643,13 +753,8 @@ impl Serde for Row {
                 attrs.push(attr);
             }
         }
-        super::crc8_u8(&mut checksum, 0u8); // placeholder for missing row-delete marker
-        if peek_and_expect(inp, super::Tag::RowChecksum) {
-            let _ = super::Tag::deserialize(inp)?;
-            let exp = u8::deserialize(inp)?;
-            if checksum != exp {
-                return issue_error();
-            }
+        if deserialize_row_tail(inp, checksum)? {
+            return issue_error();
         }
         Ok(Row{
             row_key,
@@ -658,6 +763,93 @@ impl Serde for Row {
     }
 }
 
+impl Serde for RowChange {
+    fn serialize(&self, out: &mut dyn BufMut) {
+        let mut checksum = 0u8;
+        self.row_key.serialize_crc8(out, &mut checksum);
+        if !self.cells.is_empty() {
+            super::Tag::RowData.serialize(out);
+            for x in self.cells.iter() {
+                x.serialize_crc8(out, &mut checksum);
+            }
+        }
+        serialize_row_tail(out, checksum, false);
+    }
+
+    fn deserialize(inp: &mut dyn Buf) -> Result<Self, Error> {
+        let mut checksum = 0u8;
+        let row_key = RowKey::deserialize_crc8(inp, &mut checksum)?;
+        let mut cells = vec![];
+        if peek_and_expect(inp, super::Tag::RowData) {
+            let _ = super::Tag::deserialize(inp)?;
+            while peek_and_expect(inp, super::Tag::Cell) {
+                let cell = CellChange::deserialize_crc8(inp, &mut checksum)?;
+                cells.push(cell);
+            }
+        }
+        if deserialize_row_tail(inp, checksum)? {
+            return issue_error();
+        }
+        Ok(RowChange{
+            row_key,
+            cells,
+        })
+    }
+}
+
+impl Serde for super::RowDeletion {
+    fn serialize(&self, out: &mut dyn BufMut) {
+        let mut checksum = 0u8;
+        self.0.serialize_crc8(out, &mut checksum);
+        serialize_row_tail(out, checksum, true);
+    }
+
+    fn deserialize(inp: &mut dyn Buf) -> Result<Self, Error> {
+        let mut checksum = 0u8;
+        let row_key = RowKey::deserialize_crc8(inp, &mut checksum)?;
+        if !deserialize_row_tail(inp, checksum)? {
+            return issue_error();
+        }
+        Ok(super::RowDeletion(row_key))
+    }
+}
+
+pub(in crate::plainbuffer) fn serialize_row_tail(
+    out: &mut dyn BufMut,
+    mut checksum: u8,
+    delete_marker: bool,
+) {
+    if delete_marker {
+        super::Tag::RowDeleteMarker.serialize(out);
+        super::crc8_u8(&mut checksum, 1u8);
+    } else {
+        super::crc8_u8(&mut checksum, 0u8);
+    }
+    super::Tag::RowChecksum.serialize(out);
+    checksum.serialize(out);
+}
+
+pub(in crate::plainbuffer) fn deserialize_row_tail(
+    inp: &mut dyn Buf,
+    mut checksum: u8,
+) -> Result<bool, Error> {
+    let delete_marker = if peek_and_expect(inp, super::Tag::RowDeleteMarker) {
+        let _ = super::Tag::deserialize(inp)?;
+        true
+    } else {
+        false
+    };
+    super::crc8_u8(&mut checksum, delete_marker as u8);
+    if peek_and_expect(inp, super::Tag::RowChecksum) {
+        let _ = super::Tag::deserialize(inp)?;
+        let exp = u8::deserialize(inp)?;
+        if checksum != exp {
+            return issue_error();
+        }
+    }
+    Ok(delete_marker)
+}
+
 fn peek_and_expect(inp: &mut dyn Buf, exp: super::Tag) -> bool {
     if let Ok(tag) = peek_tag(inp) {
         if tag == exp {
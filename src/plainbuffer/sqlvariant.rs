@@ -0,0 +1,124 @@
+use bytes::{Bytes, Buf, BufMut};
+use crate::Error;
+use crate::types::*;
+use std::convert::TryFrom;
+use super::Serde;
+use super::serde::issue_error;
+
+// A SQLVariant is a PlainBuffer cell value without tag, length prefix and checksum.
+pub(crate) trait SqlVariant: Sized {
+    fn to_sql_variant(&self) -> Vec<u8>;
+    fn from_sql_variant(buf: Bytes) -> Result<Self, Error>;
+}
+
+impl SqlVariant for ExtendedRowKeyValue {
+    fn to_sql_variant(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = vec![];
+        serialize_rowkey_value(self, &mut buf);
+        buf
+    }
+
+    fn from_sql_variant(mut buf: Bytes) -> Result<Self, Error> {
+        let res = deserialize_rowkey_value(&mut buf)?;
+        if buf.has_remaining() {
+            return issue_error();
+        }
+        Ok(res)
+    }
+}
+
+impl SqlVariant for AttrValue {
+    fn to_sql_variant(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = vec![];
+        serialize_attr_value(self, &mut buf);
+        buf
+    }
+
+    fn from_sql_variant(mut buf: Bytes) -> Result<Self, Error> {
+        let res = deserialize_attr_value(&mut buf)?;
+        if buf.has_remaining() {
+            return issue_error();
+        }
+        Ok(res)
+    }
+}
+
+fn serialize_rowkey_value(x: &ExtendedRowKeyValue, out: &mut dyn BufMut) {
+    match x {
+        ExtendedRowKeyValue::Int(x) => {
+            super::VariantType::Integer.serialize(out);
+            x.serialize(out);
+        }
+        ExtendedRowKeyValue::Str(x) => {
+            super::VariantType::String.serialize(out);
+            x.serialize(out);
+        }
+        ExtendedRowKeyValue::Blob(x) => {
+            super::VariantType::Blob.serialize(out);
+            x.serialize(out);
+        }
+        ExtendedRowKeyValue::InfMin => {
+            super::VariantType::InfMin.serialize(out);
+        }
+        ExtendedRowKeyValue::InfMax => {
+            super::VariantType::InfMax.serialize(out);
+        }
+        ExtendedRowKeyValue::AutoIncr => {
+            super::VariantType::AutoIncrement.serialize(out);
+        }
+    }
+}
+
+fn deserialize_rowkey_value(inp: &mut dyn Buf) -> Result<ExtendedRowKeyValue, Error> {
+    let vt = super::VariantType::try_from(u8::deserialize(inp)?)?;
+    match vt {
+        super::VariantType::Integer => Ok(ExtendedRowKeyValue::Int(i64::deserialize(inp)?)),
+        super::VariantType::String => Ok(ExtendedRowKeyValue::Str(String::deserialize(inp)?)),
+        super::VariantType::Blob => Ok(ExtendedRowKeyValue::Blob(Bytes::deserialize(inp)?)),
+        super::VariantType::InfMin => Ok(ExtendedRowKeyValue::InfMin),
+        super::VariantType::InfMax => Ok(ExtendedRowKeyValue::InfMax),
+        super::VariantType::AutoIncrement => Ok(ExtendedRowKeyValue::AutoIncr),
+        _ => issue_error(),
+    }
+}
+
+fn serialize_attr_value(x: &AttrValue, out: &mut dyn BufMut) {
+    match x {
+        AttrValue::Int(x) => {
+            super::VariantType::Integer.serialize(out);
+            x.serialize(out);
+        }
+        AttrValue::Str(x) => {
+            super::VariantType::String.serialize(out);
+            x.serialize(out);
+        }
+        AttrValue::Blob(x) => {
+            super::VariantType::Blob.serialize(out);
+            x.serialize(out);
+        }
+        AttrValue::Bool(x) => {
+            super::VariantType::Boolean.serialize(out);
+            (*x as u8).serialize(out);
+        }
+        AttrValue::Float(x) => {
+            super::VariantType::Double.serialize(out);
+            x.to_bits().serialize(out);
+        }
+        AttrValue::Null => {
+            super::VariantType::Null.serialize(out);
+        }
+    }
+}
+
+fn deserialize_attr_value(inp: &mut dyn Buf) -> Result<AttrValue, Error> {
+    let vt = super::VariantType::try_from(u8::deserialize(inp)?)?;
+    match vt {
+        super::VariantType::Integer => Ok(AttrValue::Int(i64::deserialize(inp)?)),
+        super::VariantType::String => Ok(AttrValue::Str(String::deserialize(inp)?)),
+        super::VariantType::Blob => Ok(AttrValue::Blob(Bytes::deserialize(inp)?)),
+        super::VariantType::Boolean => Ok(AttrValue::Bool(u8::deserialize(inp)? > 0)),
+        super::VariantType::Double => Ok(AttrValue::Float(f64::from_bits(u64::deserialize(inp)?))),
+        super::VariantType::Null => Ok(AttrValue::Null),
+        _ => issue_error(),
+    }
+}
@@ -1,3 +1,5 @@
 mod crc;
 mod primitives;
 mod rows;
+mod row_changes;
+mod sqlvariant;
@@ -0,0 +1,82 @@
+use bytes::{Bytes, BytesMut};
+use crate::plainbuffer as pbuf;
+use crate::types::*;
+use pbuf::{SerdeWithCrc8, PbufSerde};
+
+fn flip_row_checksum(buf: Vec<u8>) -> Bytes {
+    let mut buf = buf;
+    let n = buf.len();
+    buf[n - 1] = !buf[n - 1];
+    Bytes::from(buf)
+}
+
+#[quickcheck]
+fn rowchange_checksum_is_verified(oracle: RowChange) {
+    let buf = flip_row_checksum(oracle.to_pbuf());
+    assert!(RowChange::from_pbuf(buf).is_err());
+}
+
+#[quickcheck]
+fn rowdeletion_checksum_covers_delete_marker(oracle: RowKey) {
+    let oracle = pbuf::RowDeletion(oracle);
+    let buf = oracle.to_pbuf();
+    assert!(pbuf::RowDeletion::from_pbuf(flip_row_checksum(buf.clone())).is_err());
+
+    // dropping the delete marker must break the row checksum
+    let n = buf.len();
+    let mut stripped = buf[..n - 3].to_vec();
+    stripped.extend_from_slice(&buf[n - 2..]);
+    assert!(Row::from_pbuf(Bytes::from(stripped)).is_err());
+}
+
+#[quickcheck]
+fn cellchange_checksum_covers_cell_type(name: Name, delta: i64) {
+    // an increment differs from a put of the same value only in its cell type
+    let cell_checksum = |x: CellChange| {
+        let mut chksum = 0u8;
+        let mut buf = BytesMut::new();
+        x.serialize_crc8(&mut buf, &mut chksum);
+        buf[buf.len() - 1]
+    };
+    let put = cell_checksum(CellChange::Put(Attribute{
+        name: name.clone(),
+        value: AttrValue::Int(delta),
+        timestamp: AttrTimestamp::ServerAttach,
+    }));
+    let incr = cell_checksum(CellChange::Increment(name, delta));
+    let mut oracle = put;
+    pbuf::crc8_u8(&mut oracle, 0x4); // INCREMENT
+    assert_eq!(incr, oracle);
+}
+
+#[quickcheck]
+fn rowchange_with_null(row_key: RowKey, name: Name) {
+    let oracle = RowChange::new(row_key)
+        .put(Attribute{
+            name,
+            value: AttrValue::Null,
+            timestamp: AttrTimestamp::ServerAttach,
+        });
+    let buf = Bytes::from(oracle.to_pbuf());
+    let trial = RowChange::from_pbuf(buf).unwrap();
+    assert_eq!(oracle, trial);
+}
+
+#[test]
+fn attrvalue_layout() {
+    let mut chksum = 0u8;
+    let mut buf = BytesMut::new();
+    AttrValue::Str("ab".to_string()).serialize_crc8(&mut buf, &mut chksum);
+    assert_eq!(buf.to_vec(), vec![0x5, 7, 0, 0, 0, 0x3, 2, 0, 0, 0, b'a', b'b']);
+
+    let mut buf = BytesMut::new();
+    AttrValue::Null.serialize_crc8(&mut buf, &mut chksum);
+    assert_eq!(buf.to_vec(), vec![0x5, 1, 0, 0, 0, 0x6]);
+}
+
+#[test]
+fn attrvalue_length_prefix_is_verified() {
+    let buf = vec![0x5, 3, 0, 0, 0, 0x3, 2, 0, 0, 0, b'a', b'b'];
+    let mut chksum = 0u8;
+    assert!(AttrValue::deserialize_crc8(&mut Bytes::from(buf), &mut chksum).is_err());
+}
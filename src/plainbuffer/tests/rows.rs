@@ -138,6 +138,24 @@ fn pbufserde_row(oracle: Row) {
     assert_eq!(oracle, trial);
 }
 
+#[quickcheck]
+fn pbufserde_rowkey(oracle: RowKey) {
+    let buf = Bytes::from(oracle.to_pbuf());
+    println!("{:?}", buf);
+    let trial = RowKey::from_pbuf(buf.clone()).unwrap();
+    assert_eq!(oracle, trial);
+    let row = Row::from_pbuf(buf).unwrap();
+    assert!(row.attrs.is_empty());
+}
+
+#[quickcheck]
+fn pbufserde_ext_rowkey(oracle: ExtendedRowKey) {
+    let buf = Bytes::from(oracle.to_pbuf());
+    println!("{:?}", buf);
+    let trial = ExtendedRowKey::from_pbuf(buf).unwrap();
+    assert_eq!(oracle, trial);
+}
+
 #[quickcheck]
 fn pbufserde_row_vec(oracle: Vec<Row>) {
     let buf = Bytes::from(oracle.to_pbuf());
@@ -145,3 +163,51 @@ fn pbufserde_row_vec(oracle: Vec<Row>) {
     let trial = Vec::<Row>::from_pbuf(buf).unwrap();
     assert_eq!(oracle, trial);
 }
+
+#[quickcheck]
+fn serde_cellchange(oracle: CellChange) {
+    let mut oracle_chksum = 0u8;
+    let mut buf = BytesMut::new();
+    oracle.serialize_crc8(&mut buf, &mut oracle_chksum);
+    let mut buf = Bytes::from(buf);
+    println!("{:?}", buf);
+    let mut trial_chksum = 0u8;
+    let trial = CellChange::deserialize_crc8(&mut buf, &mut trial_chksum)
+        .unwrap();
+    assert!(buf.is_empty());
+    assert_eq!(oracle, trial);
+    assert_eq!(oracle_chksum, trial_chksum);
+}
+
+#[quickcheck]
+fn pbufserde_rowchange(oracle: RowChange) {
+    let buf = Bytes::from(oracle.to_pbuf());
+    println!("{:?}", buf);
+    let trial = RowChange::from_pbuf(buf).unwrap();
+    assert_eq!(oracle, trial);
+}
+
+#[quickcheck]
+fn pbufserde_rowdeletion(oracle: RowKey) {
+    let oracle = pbuf::RowDeletion(oracle);
+    let buf = Bytes::from(oracle.to_pbuf());
+    println!("{:?}", buf);
+    let trial = pbuf::RowDeletion::from_pbuf(buf.clone()).unwrap();
+    assert_eq!(oracle, trial);
+    assert!(Row::from_pbuf(buf).is_err());
+}
+
+#[test]
+fn serde_cellchange_increment_layout() {
+    let mut chksum = 0u8;
+    let mut buf = BytesMut::new();
+    CellChange::Increment(Name::new("c"), 1).serialize_crc8(&mut buf, &mut chksum);
+    let buf = buf.to_vec();
+    assert_eq!(buf[..buf.len() - 2].to_vec(), vec![
+        0x3, // cell
+        0x4, 1, 0, 0, 0, b'c', // cell name
+        0x5, 9, 0, 0, 0, 0x0, 1, 0, 0, 0, 0, 0, 0, 0, // cell value
+        0x6, 0x4, // cell type
+    ]);
+    assert_eq!(buf[buf.len() - 2], 0xa); // cell checksum
+}
@@ -0,0 +1,31 @@
+use bytes::Bytes;
+use crate::plainbuffer::SqlVariant;
+use crate::types::*;
+
+#[quickcheck]
+fn sqlvariant_extrowkeyvalue(oracle: ExtendedRowKeyValue) {
+    let buf = oracle.to_sql_variant();
+    let trial = ExtendedRowKeyValue::from_sql_variant(Bytes::from(buf)).unwrap();
+    assert_eq!(oracle, trial);
+}
+
+#[quickcheck]
+fn sqlvariant_attrvalue(oracle: AttrValue) {
+    let buf = oracle.to_sql_variant();
+    let trial = AttrValue::from_sql_variant(Bytes::from(buf)).unwrap();
+    assert_eq!(oracle, trial);
+}
+
+#[test]
+fn sqlvariant_layout() {
+    let trial = ExtendedRowKeyValue::Str("ab".to_string()).to_sql_variant();
+    assert_eq!(trial, vec![0x3, 2, 0, 0, 0, b'a', b'b']);
+    let trial = ExtendedRowKeyValue::Int(1).to_sql_variant();
+    assert_eq!(trial, vec![0x0, 1, 0, 0, 0, 0, 0, 0, 0]);
+    let trial = ExtendedRowKeyValue::InfMin.to_sql_variant();
+    assert_eq!(trial, vec![0x9]);
+    let trial = AttrValue::Bool(true).to_sql_variant();
+    assert_eq!(trial, vec![0x2, 1]);
+    let trial = AttrValue::Float(1.0).to_sql_variant();
+    assert_eq!(trial, vec![0x1, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f]);
+}
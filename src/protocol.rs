@@ -184,6 +184,7 @@ impl<'a> From<&'a str> for RowExistenceExpectation {
 pub enum ReturnType {
     RT_NONE = 0,
     RT_PK = 1,
+    RT_AFTER_MODIFY = 2,
 }
 
 impl Default for ReturnType {
@@ -197,6 +198,7 @@ impl From<i32> for ReturnType {
         match i {
             0 => ReturnType::RT_NONE,
             1 => ReturnType::RT_PK,
+            2 => ReturnType::RT_AFTER_MODIFY,
             _ => Self::default(),
         }
     }
@@ -207,6 +209,7 @@ impl<'a> From<&'a str> for ReturnType {
         match s {
             "RT_NONE" => ReturnType::RT_NONE,
             "RT_PK" => ReturnType::RT_PK,
+            "RT_AFTER_MODIFY" => ReturnType::RT_AFTER_MODIFY,
             _ => Self::default(),
         }
     }
@@ -280,6 +283,123 @@ impl<'a> From<&'a str> for Direction {
     }
 }
 
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FilterType {
+    FT_SINGLE_COLUMN_VALUE = 1,
+    FT_COMPOSITE_COLUMN_VALUE = 2,
+    FT_COLUMN_PAGINATION = 3,
+}
+
+impl Default for FilterType {
+    fn default() -> Self {
+        FilterType::FT_SINGLE_COLUMN_VALUE
+    }
+}
+
+impl From<i32> for FilterType {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => FilterType::FT_SINGLE_COLUMN_VALUE,
+            2 => FilterType::FT_COMPOSITE_COLUMN_VALUE,
+            3 => FilterType::FT_COLUMN_PAGINATION,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FilterType {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "FT_SINGLE_COLUMN_VALUE" => FilterType::FT_SINGLE_COLUMN_VALUE,
+            "FT_COMPOSITE_COLUMN_VALUE" => FilterType::FT_COMPOSITE_COLUMN_VALUE,
+            "FT_COLUMN_PAGINATION" => FilterType::FT_COLUMN_PAGINATION,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ComparatorType {
+    CT_EQUAL = 1,
+    CT_NOT_EQUAL = 2,
+    CT_GREATER_THAN = 3,
+    CT_GREATER_EQUAL = 4,
+    CT_LESS_THAN = 5,
+    CT_LESS_EQUAL = 6,
+}
+
+impl Default for ComparatorType {
+    fn default() -> Self {
+        ComparatorType::CT_EQUAL
+    }
+}
+
+impl From<i32> for ComparatorType {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => ComparatorType::CT_EQUAL,
+            2 => ComparatorType::CT_NOT_EQUAL,
+            3 => ComparatorType::CT_GREATER_THAN,
+            4 => ComparatorType::CT_GREATER_EQUAL,
+            5 => ComparatorType::CT_LESS_THAN,
+            6 => ComparatorType::CT_LESS_EQUAL,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComparatorType {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "CT_EQUAL" => ComparatorType::CT_EQUAL,
+            "CT_NOT_EQUAL" => ComparatorType::CT_NOT_EQUAL,
+            "CT_GREATER_THAN" => ComparatorType::CT_GREATER_THAN,
+            "CT_GREATER_EQUAL" => ComparatorType::CT_GREATER_EQUAL,
+            "CT_LESS_THAN" => ComparatorType::CT_LESS_THAN,
+            "CT_LESS_EQUAL" => ComparatorType::CT_LESS_EQUAL,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LogicalOperator {
+    LO_NOT = 1,
+    LO_AND = 2,
+    LO_OR = 3,
+}
+
+impl Default for LogicalOperator {
+    fn default() -> Self {
+        LogicalOperator::LO_NOT
+    }
+}
+
+impl From<i32> for LogicalOperator {
+    fn from(i: i32) -> Self {
+        match i {
+            1 => LogicalOperator::LO_NOT,
+            2 => LogicalOperator::LO_AND,
+            3 => LogicalOperator::LO_OR,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LogicalOperator {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "LO_NOT" => LogicalOperator::LO_NOT,
+            "LO_AND" => LogicalOperator::LO_AND,
+            "LO_OR" => LogicalOperator::LO_OR,
+            _ => Self::default(),
+        }
+    }
+}
+
 #[derive(Debug, Default, PartialEq, Clone)]
 pub struct Error {
     pub code: String,
@@ -1063,6 +1183,7 @@ impl MessageWrite for TimeRange {
 #[derive(Debug, Default, PartialEq, Clone)]
 pub struct ReturnContent {
     pub return_type: Option<ReturnType>,
+    pub return_column_names: Vec<String>,
 }
 
 impl<'a> MessageRead<'a> for ReturnContent {
@@ -1071,6 +1192,7 @@ impl<'a> MessageRead<'a> for ReturnContent {
         while !r.is_eof() {
             match r.next_tag(bytes) {
                 Ok(8) => msg.return_type = Some(r.read_enum(bytes)?),
+                Ok(18) => msg.return_column_names.push(r.read_string(bytes)?.to_owned()),
                 Ok(t) => { r.read_unknown(bytes, t)?; }
                 Err(e) => return Err(e),
             }
@@ -1083,10 +1205,12 @@ impl MessageWrite for ReturnContent {
     fn get_size(&self) -> usize {
         0
         + self.return_type.as_ref().map_or(0, |m| 1 + sizeof_varint(*(m) as u64))
+        + self.return_column_names.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
     }
 
     fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
         if let Some(ref s) = self.return_type { w.write_with_tag(8, |w| w.write_enum(*s as i32))?; }
+        for s in &self.return_column_names { w.write_with_tag(18, |w| w.write_string(&**s))?; }
         Ok(())
     }
 }
@@ -2111,3 +2235,155 @@ impl MessageWrite for SplitLocation {
 
 }
 
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SingleColumnValueFilter {
+    pub comparator: ComparatorType,
+    pub column_name: String,
+    pub column_value: Vec<u8>,
+    pub filter_if_missing: bool,
+    pub latest_version_only: bool,
+}
+
+impl<'a> MessageRead<'a> for SingleColumnValueFilter {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.comparator = r.read_enum(bytes)?,
+                Ok(18) => msg.column_name = r.read_string(bytes)?.to_owned(),
+                Ok(26) => msg.column_value = r.read_bytes(bytes)?.to_owned(),
+                Ok(32) => msg.filter_if_missing = r.read_bool(bytes)?,
+                Ok(40) => msg.latest_version_only = r.read_bool(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for SingleColumnValueFilter {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.comparator) as u64)
+        + 1 + sizeof_len((&self.column_name).len())
+        + 1 + sizeof_len((&self.column_value).len())
+        + 1 + sizeof_varint(*(&self.filter_if_missing) as u64)
+        + 1 + sizeof_varint(*(&self.latest_version_only) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_enum(*&self.comparator as i32))?;
+        w.write_with_tag(18, |w| w.write_string(&**&self.column_name))?;
+        w.write_with_tag(26, |w| w.write_bytes(&**&self.column_value))?;
+        w.write_with_tag(32, |w| w.write_bool(*&self.filter_if_missing))?;
+        w.write_with_tag(40, |w| w.write_bool(*&self.latest_version_only))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CompositeColumnValueFilter {
+    pub combinator: LogicalOperator,
+    pub sub_filters: Vec<Filter>,
+}
+
+impl<'a> MessageRead<'a> for CompositeColumnValueFilter {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.combinator = r.read_enum(bytes)?,
+                Ok(18) => msg.sub_filters.push(r.read_message::<Filter>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for CompositeColumnValueFilter {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.combinator) as u64)
+        + self.sub_filters.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_enum(*&self.combinator as i32))?;
+        for s in &self.sub_filters { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct ColumnPaginationFilter {
+    pub offset: i32,
+    pub limit: i32,
+}
+
+impl<'a> MessageRead<'a> for ColumnPaginationFilter {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.offset = r.read_int32(bytes)?,
+                Ok(16) => msg.limit = r.read_int32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for ColumnPaginationFilter {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.offset) as u64)
+        + 1 + sizeof_varint(*(&self.limit) as u64)
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_int32(*&self.offset))?;
+        w.write_with_tag(16, |w| w.write_int32(*&self.limit))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Filter {
+    pub type_pb: FilterType,
+    pub filter: Vec<u8>,
+}
+
+impl<'a> MessageRead<'a> for Filter {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.type_pb = r.read_enum(bytes)?,
+                Ok(18) => msg.filter = r.read_bytes(bytes)?.to_owned(),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl MessageWrite for Filter {
+    fn get_size(&self) -> usize {
+        0
+        + 1 + sizeof_varint(*(&self.type_pb) as u64)
+        + 1 + sizeof_len((&self.filter).len())
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        w.write_with_tag(8, |w| w.write_enum(*&self.type_pb as i32))?;
+        w.write_with_tag(18, |w| w.write_bytes(&**&self.filter))?;
+        Ok(())
+    }
+}
+
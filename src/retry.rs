@@ -55,6 +55,11 @@ impl RetryCategory {
             RetryCategory::Depends => {
                 match act {
                     Action::ListTable => true,
+                    Action::DescribeTable => true,
+                    Action::ComputeSplitPointsBySize => true,
+                    Action::GetRow => true,
+                    Action::GetRange => true,
+                    Action::BatchGetRow => true,
                     _ => false,
                 }
             }
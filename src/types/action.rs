@@ -6,6 +6,17 @@ pub enum Action {
     DeleteTable,
     ListTable,
     PutRow,
+    GetRow,
+    UpdateRow,
+    DeleteRow,
+    GetRange,
+    BatchWriteRow,
+    BatchGetRow,
+    DescribeTable,
+    UpdateTable,
+    ComputeSplitPointsBySize,
+    LoadTable,
+    UnloadTable,
 }
 
 impl ToString for Action {
@@ -15,6 +26,17 @@ impl ToString for Action {
             Action::DeleteTable => "/DeleteTable".to_string(),
             Action::ListTable => "/ListTable".to_string(),
             Action::PutRow => "/PutRow".to_string(),
+            Action::GetRow => "/GetRow".to_string(),
+            Action::UpdateRow => "/UpdateRow".to_string(),
+            Action::DeleteRow => "/DeleteRow".to_string(),
+            Action::GetRange => "/GetRange".to_string(),
+            Action::BatchWriteRow => "/BatchWriteRow".to_string(),
+            Action::BatchGetRow => "/BatchGetRow".to_string(),
+            Action::DescribeTable => "/DescribeTable".to_string(),
+            Action::UpdateTable => "/UpdateTable".to_string(),
+            Action::ComputeSplitPointsBySize => "/ComputeSplitPointsBySize".to_string(),
+            Action::LoadTable => "/LoadTable".to_string(),
+            Action::UnloadTable => "/UnloadTable".to_string(),
         }
     }
 }
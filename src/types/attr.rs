@@ -1,4 +1,6 @@
 use bytes::Bytes;
+use crate::{Error, ErrorCode};
+use std::convert::TryFrom;
 use super::*;
 
 #[cfg(test)]
@@ -11,6 +13,7 @@ pub enum AttrValue {
     Blob(Bytes),
     Bool(bool),
     Float(f64),
+    Null,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -49,18 +52,25 @@ impl PartialEq for AttrValue {
                 AttrValue::Float(y) => x.to_be_bytes() == y.to_be_bytes(),
                 _ => false,
             }
+            AttrValue::Null => matches!(other, AttrValue::Null),
         }
     }
 }
 
 impl Eq for AttrValue {}
 
-impl From<RowKeyValue> for AttrValue {
-    fn from(x: RowKeyValue) -> Self {
+impl TryFrom<RowKeyValue> for AttrValue {
+    type Error = Error;
+
+    fn try_from(x: RowKeyValue) -> Result<Self, Error> {
         match x {
-            RowKeyValue::Blob(x) => AttrValue::Blob(x),
-            RowKeyValue::Int(x) => AttrValue::Int(x),
-            RowKeyValue::Str(x) => AttrValue::Str(x),
+            RowKeyValue::Blob(x) => Ok(AttrValue::Blob(x)),
+            RowKeyValue::Int(x) => Ok(AttrValue::Int(x)),
+            RowKeyValue::Str(x) => Ok(AttrValue::Str(x)),
+            RowKeyValue::AutoIncr => Err(Error{
+                code: ErrorCode::ClientUnknown,
+                message: "Cannot convert AutoIncr to AttrValue".to_string(),
+            }),
         }
     }
 }
@@ -68,12 +78,13 @@ impl From<RowKeyValue> for AttrValue {
 #[cfg(test)]
 impl Arbitrary for AttrValue {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
-        match g.next_u32() % 5 {
+        match g.next_u32() % 6 {
             0 => AttrValue::Int(i64::arbitrary(g)),
             1 => AttrValue::Str(String::arbitrary(g)),
             2 => AttrValue::Blob(Bytes::from(Vec::<u8>::arbitrary(g))),
             3 => AttrValue::Bool(bool::arbitrary(g)),
             4 => AttrValue::Float(f64::arbitrary(g)),
+            5 => AttrValue::Null,
             _ => unimplemented!()
         }
     }
@@ -0,0 +1,287 @@
+use bytes::Bytes;
+use crate::{Error, ErrorCode};
+use crate::protocol as pb;
+use crate::plainbuffer::PbufSerde;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct TableInBatchGet {
+    pub table_name: Name,
+    pub row_keys: Vec<RowKey>,
+    pub columns_to_get: Vec<Name>,
+    pub max_versions: Option<i32>,
+    pub time_range: Option<TimeRange>,
+    pub filter: Option<Filter>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchGetRowRequest {
+    pub tables: Vec<TableInBatchGet>,
+}
+
+impl TableInBatchGet {
+    pub fn new<T: ToString>(table_name: T, row_keys: Vec<RowKey>) -> Self {
+        Self{
+            table_name: Name::new(table_name),
+            row_keys,
+            columns_to_get: vec![],
+            max_versions: Some(1),
+            time_range: None,
+            filter: None,
+        }
+    }
+}
+
+impl BatchGetRowRequest {
+    pub fn new() -> Self {
+        Self{
+            tables: vec![],
+        }
+    }
+}
+
+impl Default for BatchGetRowRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableInBatchGetResponse {
+    pub table_name: Name,
+    pub rows: Vec<Result<Option<Row>, Error>>,
+    pub consumed: Vec<ConsumedCapacity>, // one for each row
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchGetRowResponse {
+    pub base: super::BaseResponse,
+    pub tables: Vec<TableInBatchGetResponse>,
+}
+
+impl super::BatchRequest for BatchGetRowRequest {
+    fn select(&self, positions: &[(usize, usize)]) -> Self {
+        let mut res = BatchGetRowRequest::new();
+        let mut last = None;
+        for &(i, j) in positions.iter() {
+            if last != Some(i) {
+                last = Some(i);
+                res.tables.push(TableInBatchGet{
+                    row_keys: vec![],
+                    ..self.tables[i].clone()
+                });
+            }
+            res.tables.last_mut().unwrap().row_keys.push(self.tables[i].row_keys[j].clone());
+        }
+        res
+    }
+}
+
+impl super::BatchResponse for BatchGetRowResponse {
+    type Request = BatchGetRowRequest;
+
+    fn check_against(&self, req: &BatchGetRowRequest) -> Result<(), Error> {
+        let matched = self.tables.len() == req.tables.len()
+            && self.tables.iter()
+                .zip(req.tables.iter())
+                .all(|(x, y)| {
+                    x.table_name == y.table_name && x.rows.len() == y.row_keys.len()
+                });
+        if !matched {
+            return Err(Error{
+                code: ErrorCode::CorruptedResponse,
+                message: "Rows in BatchGetRow response mismatch the request.".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn tables_mut(&mut self) -> Vec<BatchRowsMut<'_>> {
+        self.tables
+            .iter_mut()
+            .map(|x| {
+                (&mut x.rows, &mut x.consumed)
+            })
+            .collect()
+    }
+}
+
+impl From<TableInBatchGet> for pb::TableInBatchGetRowRequest {
+    fn from(x: TableInBatchGet) -> pb::TableInBatchGetRowRequest {
+        pb::TableInBatchGetRowRequest{
+            table_name: x.table_name.into(),
+            primary_key: x.row_keys
+                .iter()
+                .map(|x| {
+                    x.to_pbuf()
+                })
+                .collect(),
+            token: vec![],
+            columns_to_get: x.columns_to_get
+                .into_iter()
+                .map(|x| {
+                    x.into()
+                })
+                .collect(),
+            time_range: x.time_range.map(|x| x.into()),
+            max_versions: x.max_versions,
+            cache_blocks: true,
+            filter: x.filter.map(encode_filter),
+            start_column: None,
+            end_column: None,
+        }
+    }
+}
+
+impl From<BatchGetRowRequest> for pb::BatchGetRowRequest {
+    fn from(x: BatchGetRowRequest) -> pb::BatchGetRowRequest {
+        pb::BatchGetRowRequest{
+            tables: x.tables
+                .into_iter()
+                .map(|x| {
+                    x.into()
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<pb::RowInBatchGetRowResponse> for Result<Option<Row>, Error> {
+    fn from(x: pb::RowInBatchGetRowResponse) -> Result<Option<Row>, Error> {
+        if !x.is_ok {
+            return match x.error {
+                Some(err) => Err(err.into()),
+                None => Err(Error{
+                    code: ErrorCode::OTSUnknown,
+                    message: String::new(),
+                }),
+            };
+        }
+        match x.row {
+            Some(row) if !row.is_empty() => {
+                let row = Row::from_pbuf(Bytes::from(row))?;
+                Ok(Some(row))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+impl From<pb::BatchGetRowResponse> for BatchGetRowResponse {
+    fn from(x: pb::BatchGetRowResponse) -> BatchGetRowResponse {
+        BatchGetRowResponse{
+            base: super::BaseResponse::default(),
+            tables: x.tables
+                .into_iter()
+                .map(|x| {
+                    TableInBatchGetResponse{
+                        table_name: x.table_name.into(),
+                        consumed: x.rows
+                            .iter()
+                            .map(|x| {
+                                x.consumed.clone().into()
+                            })
+                            .collect(),
+                        rows: x.rows
+                            .into_iter()
+                            .map(|x| {
+                                x.into()
+                            })
+                            .collect(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<BatchGetRowRequest> for Bytes {
+    fn from(x: BatchGetRowRequest) -> Bytes {
+        serialize_request::<BatchGetRowRequest, pb::BatchGetRowRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for BatchGetRowResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        new_response::<Self, pb::BatchGetRowResponse>(&v)
+    }
+}
+
+impl super::Request for BatchGetRowRequest {
+    fn action(&self) -> Action {
+        Action::BatchGetRow
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for BatchGetRowResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+
+    fn consumed(&self) -> ConsumedCapacity {
+        let mut res = ConsumedCapacity::default();
+        self.tables.iter()
+            .flat_map(|x| {
+                x.consumed.iter()
+            })
+            .for_each(|x| {
+                res += *x;
+            });
+        res
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[quickcheck]
+    fn batchgetrow_maps_rows_to_results(oracle: Row) {
+        let req = BatchGetRowRequest{
+            tables: vec![
+                TableInBatchGet::new("t0", vec![oracle.row_key.clone(); 3]),
+            ],
+        };
+        let pb_resp = pb::BatchGetRowResponse{
+            tables: vec![pb::TableInBatchGetRowResponse{
+                table_name: "t0".to_string(),
+                rows: vec![
+                    pb::RowInBatchGetRowResponse{
+                        is_ok: true,
+                        row: Some(oracle.to_pbuf()),
+                        ..Default::default()
+                    },
+                    pb::RowInBatchGetRowResponse{
+                        is_ok: true,
+                        row: Some(vec![]),
+                        ..Default::default()
+                    },
+                    pb::RowInBatchGetRowResponse{
+                        is_ok: false,
+                        error: Some(pb::Error{
+                            code: "OTSObjectNotExist".to_string(),
+                            message: None,
+                        }),
+                        ..Default::default()
+                    },
+                ],
+            }],
+        };
+        let resp = BatchGetRowResponse::from(pb_resp);
+        assert!(resp.check_against(&req).is_ok());
+        let rows = &resp.tables[0].rows;
+        assert_eq!(rows[0].as_ref().unwrap(), &Some(oracle));
+        assert_eq!(rows[1].as_ref().unwrap(), &None);
+        match &rows[2] {
+            Err(Error{code: ErrorCode::OTSObjectNotExist, ..}) => {}
+            x => panic!("unexpected {:?}", x),
+        }
+    }
+}
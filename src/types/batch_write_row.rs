@@ -0,0 +1,360 @@
+use bytes::Bytes;
+use crate::{Error, ErrorCode};
+use crate::protocol as pb;
+use crate::plainbuffer::{PbufSerde, RowDeletion};
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub enum WriteOperation {
+    Put(Row),
+    Update(RowChange),
+    Delete(RowKey),
+}
+
+#[derive(Debug, Clone)]
+pub struct RowInBatchWrite {
+    pub operation: WriteOperation,
+    pub condition: Condition,
+    pub in_return: InReturn,
+}
+
+#[derive(Debug, Clone)]
+pub struct TableInBatchWrite {
+    pub table_name: Name,
+    pub rows: Vec<RowInBatchWrite>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchWriteRowRequest {
+    pub tables: Vec<TableInBatchWrite>,
+    pub resubmit_failed_rows: bool,
+}
+
+impl RowInBatchWrite {
+    pub fn new(operation: WriteOperation) -> Self {
+        Self{
+            operation,
+            condition: Condition::new(RowExistenceExpectation::Ignore),
+            in_return: InReturn::Nothing,
+        }
+    }
+}
+
+impl BatchWriteRowRequest {
+    pub fn new() -> Self {
+        Self{
+            tables: vec![],
+            resubmit_failed_rows: false,
+        }
+    }
+
+    pub fn push<T: ToString>(&mut self, table_name: T, row: RowInBatchWrite) {
+        let table_name = Name::new(table_name);
+        match self.tables.iter_mut().find(|x| x.table_name == table_name) {
+            Some(table) => {
+                table.rows.push(row);
+            }
+            None => {
+                self.tables.push(TableInBatchWrite{
+                    table_name,
+                    rows: vec![row],
+                });
+            }
+        }
+    }
+}
+
+impl Default for BatchWriteRowRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableInBatchWriteResponse {
+    pub table_name: Name,
+    pub rows: Vec<Result<Option<Row>, Error>>, // rows iff InReturn::RowKey or InReturn::AfterModify
+    pub consumed: Vec<ConsumedCapacity>, // one for each row
+}
+
+#[derive(Debug, Clone)]
+pub struct BatchWriteRowResponse {
+    pub base: super::BaseResponse,
+    pub tables: Vec<TableInBatchWriteResponse>,
+}
+
+impl super::BatchRequest for BatchWriteRowRequest {
+    fn select(&self, positions: &[(usize, usize)]) -> Self {
+        let mut res = BatchWriteRowRequest::new();
+        let mut last = None;
+        for &(i, j) in positions.iter() {
+            if last != Some(i) {
+                last = Some(i);
+                res.tables.push(TableInBatchWrite{
+                    table_name: self.tables[i].table_name.clone(),
+                    rows: vec![],
+                });
+            }
+            res.tables.last_mut().unwrap().rows.push(self.tables[i].rows[j].clone());
+        }
+        res
+    }
+}
+
+impl super::BatchResponse for BatchWriteRowResponse {
+    type Request = BatchWriteRowRequest;
+
+    fn check_against(&self, req: &BatchWriteRowRequest) -> Result<(), Error> {
+        let matched = self.tables.len() == req.tables.len()
+            && self.tables.iter()
+                .zip(req.tables.iter())
+                .all(|(x, y)| {
+                    x.table_name == y.table_name && x.rows.len() == y.rows.len()
+                });
+        if !matched {
+            return Err(Error{
+                code: ErrorCode::CorruptedResponse,
+                message: "Rows in BatchWriteRow response mismatch the request.".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn tables_mut(&mut self) -> Vec<BatchRowsMut<'_>> {
+        self.tables
+            .iter_mut()
+            .map(|x| {
+                (&mut x.rows, &mut x.consumed)
+            })
+            .collect()
+    }
+}
+
+impl From<RowInBatchWrite> for pb::RowInBatchWriteRowRequest {
+    fn from(x: RowInBatchWrite) -> pb::RowInBatchWriteRowRequest {
+        let (type_pb, row_change) = match x.operation {
+            WriteOperation::Put(row) => (pb::OperationType::PUT, row.to_pbuf()),
+            WriteOperation::Update(change) => (pb::OperationType::UPDATE, change.to_pbuf()),
+            WriteOperation::Delete(row_key) => {
+                (pb::OperationType::DELETE, RowDeletion(row_key).to_pbuf())
+            }
+        };
+        pb::RowInBatchWriteRowRequest{
+            type_pb,
+            row_change,
+            condition: x.condition.into(),
+            return_content: Some(x.in_return.into()),
+        }
+    }
+}
+
+impl From<BatchWriteRowRequest> for pb::BatchWriteRowRequest {
+    fn from(x: BatchWriteRowRequest) -> pb::BatchWriteRowRequest {
+        pb::BatchWriteRowRequest{
+            tables: x.tables
+                .into_iter()
+                .map(|x| {
+                    pb::TableInBatchWriteRowRequest{
+                        table_name: x.table_name.into(),
+                        rows: x.rows
+                            .into_iter()
+                            .map(|x| {
+                                x.into()
+                            })
+                            .collect(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<pb::RowInBatchWriteRowResponse> for Result<Option<Row>, Error> {
+    fn from(x: pb::RowInBatchWriteRowResponse) -> Result<Option<Row>, Error> {
+        if x.is_ok {
+            return decode_returned_row(x.row);
+        }
+        match x.error {
+            Some(err) => Err(err.into()),
+            None => Err(Error{
+                code: ErrorCode::OTSUnknown,
+                message: String::new(),
+            }),
+        }
+    }
+}
+
+impl From<pb::BatchWriteRowResponse> for BatchWriteRowResponse {
+    fn from(x: pb::BatchWriteRowResponse) -> BatchWriteRowResponse {
+        BatchWriteRowResponse{
+            base: super::BaseResponse::default(),
+            tables: x.tables
+                .into_iter()
+                .map(|x| {
+                    TableInBatchWriteResponse{
+                        table_name: x.table_name.into(),
+                        consumed: x.rows
+                            .iter()
+                            .map(|x| {
+                                x.consumed.clone().into()
+                            })
+                            .collect(),
+                        rows: x.rows
+                            .into_iter()
+                            .map(|x| {
+                                x.into()
+                            })
+                            .collect(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<BatchWriteRowRequest> for Bytes {
+    fn from(x: BatchWriteRowRequest) -> Bytes {
+        serialize_request::<BatchWriteRowRequest, pb::BatchWriteRowRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for BatchWriteRowResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        new_response::<Self, pb::BatchWriteRowResponse>(&v)
+    }
+}
+
+impl super::Request for BatchWriteRowRequest {
+    fn action(&self) -> Action {
+        Action::BatchWriteRow
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for BatchWriteRowResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+
+    fn consumed(&self) -> ConsumedCapacity {
+        let mut res = ConsumedCapacity::default();
+        self.tables.iter()
+            .flat_map(|x| {
+                x.consumed.iter()
+            })
+            .for_each(|x| {
+                res += *x;
+            });
+        res
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    fn row_key(x: i64) -> RowKey {
+        RowKey::new(vec![RowKeyColumn{
+            name: Name::new("pkey"),
+            value: RowKeyValue::Int(x),
+        }])
+    }
+
+    #[test]
+    fn batchwriterow_groups_rows_by_table() {
+        let mut req = BatchWriteRowRequest::new();
+        req.push("t0", RowInBatchWrite::new(WriteOperation::Delete(row_key(0))));
+        req.push("t1", RowInBatchWrite::new(WriteOperation::Delete(row_key(1))));
+        req.push("t0", RowInBatchWrite::new(WriteOperation::Delete(row_key(2))));
+        let trial: Vec<(String, usize)> = req.tables.iter()
+            .map(|x| {
+                (<&str>::from(&x.table_name).to_string(), x.rows.len())
+            })
+            .collect();
+        assert_eq!(trial, vec![("t0".to_string(), 2), ("t1".to_string(), 1)]);
+    }
+
+    #[test]
+    fn batchwriterow_maps_rows_to_results() {
+        let mut req = BatchWriteRowRequest::new();
+        req.push("t0", RowInBatchWrite::new(WriteOperation::Delete(row_key(0))));
+        req.push("t0", RowInBatchWrite::new(WriteOperation::Delete(row_key(1))));
+        let pb_resp = pb::BatchWriteRowResponse{
+            tables: vec![pb::TableInBatchWriteRowResponse{
+                table_name: "t0".to_string(),
+                rows: vec![
+                    pb::RowInBatchWriteRowResponse{
+                        is_ok: true,
+                        row: Some(row_key(0).to_pbuf()),
+                        consumed: Some(pb::ConsumedCapacity{
+                            capacity_unit: pb::CapacityUnit{
+                                read: Some(0),
+                                write: Some(1),
+                            },
+                        }),
+                        ..Default::default()
+                    },
+                    pb::RowInBatchWriteRowResponse{
+                        is_ok: false,
+                        error: Some(pb::Error{
+                            code: "OTSServerBusy".to_string(),
+                            message: Some("busy".to_string()),
+                        }),
+                        ..Default::default()
+                    },
+                ],
+            }],
+        };
+        let resp = BatchWriteRowResponse::from(pb_resp);
+        assert!(resp.check_against(&req).is_ok());
+        let rows = &resp.tables[0].rows;
+        assert_eq!(rows[0].as_ref().unwrap().as_ref().map(|x| &x.row_key), Some(&row_key(0)));
+        assert_eq!(resp.tables[0].consumed, vec![
+            ConsumedCapacity{read: 0, write: 1},
+            ConsumedCapacity::default(),
+        ]);
+        assert_eq!(resp.consumed(), ConsumedCapacity{read: 0, write: 1});
+        match &rows[1] {
+            Err(Error{code: ErrorCode::OTSServerBusy, message}) => {
+                assert_eq!(message, "busy");
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+
+        req.push("t1", RowInBatchWrite::new(WriteOperation::Delete(row_key(2))));
+        assert!(resp.check_against(&req).is_err());
+    }
+
+    #[test]
+    fn batchwriterow_selects_rows_in_order() {
+        let mut req = BatchWriteRowRequest::new();
+        req.push("t0", RowInBatchWrite::new(WriteOperation::Delete(row_key(0))));
+        req.push("t0", RowInBatchWrite::new(WriteOperation::Delete(row_key(1))));
+        req.push("t1", RowInBatchWrite::new(WriteOperation::Delete(row_key(2))));
+        req.push("t1", RowInBatchWrite::new(WriteOperation::Delete(row_key(3))));
+        let trial = req.select(&[(0, 1), (1, 0), (1, 1)]);
+        let trial: Vec<_> = trial.tables
+            .iter()
+            .map(|x| {
+                let keys: Vec<_> = x.rows
+                    .iter()
+                    .map(|x| match &x.operation {
+                        WriteOperation::Delete(key) => key.clone(),
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                (String::from(x.table_name.clone()), keys)
+            })
+            .collect();
+        assert_eq!(trial, vec![
+            ("t0".to_string(), vec![row_key(1)]),
+            ("t1".to_string(), vec![row_key(2), row_key(3)]),
+        ]);
+    }
+}
@@ -7,7 +7,7 @@ pub enum SettableValue<V>{
 }
 
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
 pub struct DateTime(chrono::DateTime<chrono::Utc>);
 
 impl DateTime {
@@ -32,3 +32,15 @@ impl DateTime {
         DateTime(chrono::DateTime::from_utc(tm, chrono::Utc))
     }
 }
+
+impl From<chrono::DateTime<chrono::Utc>> for DateTime {
+    fn from(tm: chrono::DateTime<chrono::Utc>) -> DateTime {
+        DateTime::from_millis(tm.timestamp_millis()) // TableStore keeps millisecond precision
+    }
+}
+
+impl From<DateTime> for chrono::DateTime<chrono::Utc> {
+    fn from(tm: DateTime) -> chrono::DateTime<chrono::Utc> {
+        tm.0
+    }
+}
@@ -0,0 +1,182 @@
+use bytes::Bytes;
+use crate::Error;
+use crate::protocol as pb;
+use crate::plainbuffer::PbufSerde;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct ComputeSplitPointsBySizeRequest {
+    pub name: Name,
+    pub split_size: i64, // in 100MB
+}
+
+#[derive(Debug, Clone)]
+pub struct ComputeSplitPointsBySizeResponse {
+    pub base: super::BaseResponse,
+    pub ranges: Vec<(ExtendedRowKey, ExtendedRowKey)>, // [start, end)
+    pub locations: Vec<String>, // one for each range
+    pub consumed: ConsumedCapacity,
+}
+
+impl From<ComputeSplitPointsBySizeRequest> for pb::ComputeSplitPointsBySizeRequest {
+    fn from(x: ComputeSplitPointsBySizeRequest) -> pb::ComputeSplitPointsBySizeRequest {
+        pb::ComputeSplitPointsBySizeRequest{
+            table_name: x.name.into(),
+            split_size: x.split_size,
+        }
+    }
+}
+
+fn pad_row_key(
+    names: &[Name],
+    prefix: Vec<ExtendedRowKeyColumn>,
+    padding: ExtendedRowKeyValue,
+) -> ExtendedRowKey {
+    let mut res = prefix;
+    for name in names.iter().skip(res.len()) {
+        res.push(ExtendedRowKeyColumn{
+            name: name.clone(),
+            value: padding.clone(),
+        });
+    }
+    ExtendedRowKey::new(res)
+}
+
+impl TryFrom<pb::ComputeSplitPointsBySizeResponse> for ComputeSplitPointsBySizeResponse {
+    type Error = Error;
+
+    fn try_from(x: pb::ComputeSplitPointsBySizeResponse) -> Result<Self, Error> {
+        let consumed = x.consumed.into();
+        let names: Vec<Name> = x.schema
+            .into_iter()
+            .map(|x| {
+                x.name.into()
+            })
+            .collect();
+        // split points carry only a prefix of the primary key
+        let mut bounds = vec![
+            pad_row_key(&names, vec![], ExtendedRowKeyValue::InfMin),
+        ];
+        for point in x.split_points.into_iter() {
+            let point = RowKey::from_pbuf(Bytes::from(point))?;
+            let prefix = point.into_iter()
+                .map(|x| {
+                    x.into()
+                })
+                .collect();
+            bounds.push(pad_row_key(&names, prefix, ExtendedRowKeyValue::InfMin));
+        }
+        bounds.push(pad_row_key(&names, vec![], ExtendedRowKeyValue::InfMax));
+        let ranges = bounds.iter()
+            .zip(bounds.iter().skip(1))
+            .map(|(start, end)| {
+                (start.clone(), end.clone())
+            })
+            .collect();
+        let locations = x.locations
+            .into_iter()
+            .flat_map(|x| {
+                let location = x.location;
+                (0..x.repeat).map(move |_| location.clone())
+            })
+            .collect();
+        Ok(ComputeSplitPointsBySizeResponse{
+            base: super::BaseResponse::default(),
+            ranges,
+            locations,
+            consumed,
+        })
+    }
+}
+
+impl From<ComputeSplitPointsBySizeRequest> for Bytes {
+    fn from(x: ComputeSplitPointsBySizeRequest) -> Bytes {
+        serialize_request::<ComputeSplitPointsBySizeRequest, pb::ComputeSplitPointsBySizeRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for ComputeSplitPointsBySizeResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        try_new_response::<Self, pb::ComputeSplitPointsBySizeResponse>(&v)
+    }
+}
+
+impl super::Request for ComputeSplitPointsBySizeRequest {
+    fn action(&self) -> Action {
+        Action::ComputeSplitPointsBySize
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for ComputeSplitPointsBySizeResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+
+    fn consumed(&self) -> ConsumedCapacity {
+        self.consumed
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn splitpoints_are_padded() {
+        let schema = vec![
+            PkeyColumnSchema{
+                name: Name::new("pk0"),
+                type_: PkeyValueType::Str,
+            },
+            PkeyColumnSchema{
+                name: Name::new("pk1"),
+                type_: PkeyValueType::Blob,
+            },
+        ];
+        let point = RowKey::new(vec![RowKeyColumn{
+            name: Name::new("pk0"),
+            value: RowKeyValue::Str("m".to_string()),
+        }]);
+        let pb_resp = pb::ComputeSplitPointsBySizeResponse{
+            schema: schema.into_iter()
+                .map(|x| {
+                    x.into()
+                })
+                .collect(),
+            split_points: vec![point.to_pbuf()],
+            locations: vec![pb::mod_ComputeSplitPointsBySizeResponse::SplitLocation{
+                location: "host".to_string(),
+                repeat: 2,
+            }],
+            ..Default::default()
+        };
+        let trial = ComputeSplitPointsBySizeResponse::try_from(pb_resp).unwrap();
+        let key = |v0: ExtendedRowKeyValue, v1: ExtendedRowKeyValue| {
+            ExtendedRowKey::new(vec![
+                ExtendedRowKeyColumn{
+                    name: Name::new("pk0"),
+                    value: v0,
+                },
+                ExtendedRowKeyColumn{
+                    name: Name::new("pk1"),
+                    value: v1,
+                },
+            ])
+        };
+        let mid = key(
+            ExtendedRowKeyValue::Str("m".to_string()),
+            ExtendedRowKeyValue::InfMin);
+        assert_eq!(trial.ranges, vec![
+            (key(ExtendedRowKeyValue::InfMin, ExtendedRowKeyValue::InfMin), mid.clone()),
+            (mid, key(ExtendedRowKeyValue::InfMax, ExtendedRowKeyValue::InfMax)),
+        ]);
+        assert_eq!(trial.locations, vec!["host".to_string(), "host".to_string()]);
+    }
+}
@@ -1,4 +1,7 @@
+use crate::Error;
 use crate::protocol as pb;
+use std::convert::TryFrom;
+use super::*;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum RowExistenceExpectation {
@@ -27,16 +30,17 @@ impl From<RowExistenceExpectation> for pb::RowExistenceExpectation {
     }
 }
 
-
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Condition {
     pub row_exist: RowExistenceExpectation,
+    pub column_condition: Option<Filter>,
 }
 
 impl Condition {
     pub fn new(row_exist: RowExistenceExpectation) -> Self {
         Self{
             row_exist,
+            column_condition: None,
         }
     }
 }
@@ -45,18 +49,22 @@ impl From<Condition> for pb::Condition {
     fn from(x: Condition) -> Self {
         Self{
             row_existence: x.row_exist.into(),
-            column_condition: None,
+            column_condition: x.column_condition.map(encode_filter),
         }
     }
 }
 
-impl From<pb::Condition> for Condition {
-    fn from(x: pb::Condition) -> Self {
-        if x.column_condition.is_some() {
-            warn!("Column conditions are not supported yet.");
-        }
-        Self{
+impl TryFrom<pb::Condition> for Condition {
+    type Error = Error;
+
+    fn try_from(x: pb::Condition) -> Result<Self, Error> {
+        let column_condition = match x.column_condition {
+            Some(x) => Some(decode_filter(&x)?),
+            None => None,
+        };
+        Ok(Self{
             row_exist: x.row_existence.into(),
-        }
+            column_condition,
+        })
     }
 }
@@ -0,0 +1,29 @@
+use crate::protocol as pb;
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct ConsumedCapacity {
+    pub read: i64,
+    pub write: i64,
+}
+
+impl std::ops::AddAssign for ConsumedCapacity {
+    fn add_assign(&mut self, other: ConsumedCapacity) {
+        self.read += other.read;
+        self.write += other.write;
+    }
+}
+
+impl From<pb::ConsumedCapacity> for ConsumedCapacity {
+    fn from(x: pb::ConsumedCapacity) -> Self {
+        ConsumedCapacity{
+            read: x.capacity_unit.read.unwrap_or(0).into(),
+            write: x.capacity_unit.write.unwrap_or(0).into(),
+        }
+    }
+}
+
+impl From<Option<pb::ConsumedCapacity>> for ConsumedCapacity {
+    fn from(x: Option<pb::ConsumedCapacity>) -> Self {
+        x.map(ConsumedCapacity::from).unwrap_or_default()
+    }
+}
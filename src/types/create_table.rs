@@ -1,6 +1,7 @@
 use bytes::Bytes;
-use crate::Error;
+use crate::{Error, ErrorCode};
 use crate::protocol as pb;
+use crate::plainbuffer::SqlVariant;
 use std::convert::TryFrom;
 use super::*;
 
@@ -8,6 +9,8 @@ use super::*;
 pub struct CreateTableRequest {
     pub table_meta: TableMeta,
     pub options: TableOptions,
+    pub split_points: Vec<RowKeyValue>, // on the first primary key column
+    pub wait_until_active: Option<std::time::Duration>, // timeout
 }
 
 impl CreateTableRequest {
@@ -15,8 +18,33 @@ impl CreateTableRequest {
         Self{
             table_meta,
             options: TableOptions::default_for_create(),
+            split_points: vec![],
+            wait_until_active: None,
         }
     }
+
+    // split points must be strictly increasing, without AutoIncr
+    pub(crate) fn check_split_points(&self) -> Result<(), Error> {
+        for (i, x) in self.split_points.iter().enumerate() {
+            if let RowKeyValue::AutoIncr = x {
+                return Err(Error{
+                    code: ErrorCode::ClientUnknown,
+                    message: format!("Split point #{} is AutoIncr.", i),
+                });
+            }
+        }
+        for (i, (x, y)) in self.split_points.iter().zip(self.split_points.iter().skip(1)).enumerate() {
+            let x = ExtendedRowKeyValue::from(x.clone());
+            let y = ExtendedRowKeyValue::from(y.clone());
+            if x.compare(&y) != Some(std::cmp::Ordering::Less) {
+                return Err(Error{
+                    code: ErrorCode::ClientUnknown,
+                    message: format!("Split points #{} and #{} are not strictly increasing.", i, i + 1),
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -28,13 +56,33 @@ impl From<CreateTableRequest> for pb::CreateTableRequest {
     fn from(x: CreateTableRequest) -> pb::CreateTableRequest {
         let table_meta = x.table_meta.into();
         let (opts, cu) = x.options.into();
+        let partitions = if x.split_points.is_empty() {
+            vec![]
+        } else {
+            let mut bounds = vec![ExtendedRowKeyValue::InfMin];
+            bounds.extend(x.split_points
+                .into_iter()
+                .map(|x| {
+                    ExtendedRowKeyValue::from(x)
+                }));
+            bounds.push(ExtendedRowKeyValue::InfMax);
+            bounds.iter()
+                .zip(bounds.iter().skip(1))
+                .map(|(begin, end)| {
+                    pb::PartitionRange{
+                        begin: begin.to_sql_variant(),
+                        end: end.to_sql_variant(),
+                    }
+                })
+                .collect()
+        };
         pb::CreateTableRequest{
             table_meta,
             reserved_throughput: pb::ReservedThroughput{
                 capacity_unit: cu,
             },
             table_options: Some(opts),
-            partitions: vec![],
+            partitions,
         }
     }
 }
@@ -76,3 +124,64 @@ impl super::Response for CreateTableResponse {
         &mut self.base
     }
 }
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn createtable_partitions_from_split_points() {
+        let meta = TableMeta{
+            name: Name::new("t0"),
+            schema: vec![PkeyColumnSchema{
+                name: Name::new("pk0"),
+                type_: PkeyValueType::Str,
+            }],
+        };
+        let mut req = CreateTableRequest::new(meta);
+        req.split_points = vec![
+            RowKeyValue::Str("g".to_string()),
+            RowKeyValue::Str("p".to_string()),
+        ];
+        let trial = pb::CreateTableRequest::from(req);
+        let trial: Vec<_> = trial.partitions
+            .into_iter()
+            .map(|x| {
+                let begin = ExtendedRowKeyValue::from_sql_variant(Bytes::from(x.begin)).unwrap();
+                let end = ExtendedRowKeyValue::from_sql_variant(Bytes::from(x.end)).unwrap();
+                (begin, end)
+            })
+            .collect();
+        let g = ExtendedRowKeyValue::Str("g".to_string());
+        let p = ExtendedRowKeyValue::Str("p".to_string());
+        assert_eq!(trial, vec![
+            (ExtendedRowKeyValue::InfMin, g.clone()),
+            (g, p.clone()),
+            (p, ExtendedRowKeyValue::InfMax),
+        ]);
+    }
+
+    #[test]
+    fn createtable_checks_split_points() {
+        let meta = TableMeta{
+            name: Name::new("t0"),
+            schema: vec![PkeyColumnSchema{
+                name: Name::new("pk0"),
+                type_: PkeyValueType::Int(PkeyIntTypeOption{auto_increment: false}),
+            }],
+        };
+        let mut req = CreateTableRequest::new(meta);
+        req.split_points = vec![RowKeyValue::Int(1), RowKeyValue::Int(2)];
+        assert!(req.check_split_points().is_ok());
+        let bad = vec![
+            vec![RowKeyValue::Int(2), RowKeyValue::Int(1)],
+            vec![RowKeyValue::Int(1), RowKeyValue::Int(1)],
+            vec![RowKeyValue::Int(1), RowKeyValue::Str("2".to_string())],
+            vec![RowKeyValue::AutoIncr],
+        ];
+        for x in bad.into_iter() {
+            req.split_points = x;
+            assert!(req.check_split_points().is_err(), "{:?}", req.split_points);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use bytes::Bytes;
+use crate::Error;
+use crate::protocol as pb;
+use crate::plainbuffer::{PbufSerde, RowDeletion};
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct DeleteRowRequest {
+    pub table_name: Name,
+    pub row_key: RowKey,
+    pub condition: Condition,
+    pub in_return: InReturn,
+}
+
+impl DeleteRowRequest {
+    pub fn new<T: ToString>(table_name: T, row_key: RowKey) -> Result<Self, Error> {
+        Ok(Self{
+            table_name: Name::new(table_name),
+            row_key,
+            condition: Condition::new(RowExistenceExpectation::Ignore),
+            in_return: InReturn::Nothing,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteRowResponse {
+    pub base: super::BaseResponse,
+    pub row: Option<Row>, // present iff InReturn::RowKey or InReturn::AfterModify
+    pub consumed: ConsumedCapacity,
+}
+
+impl From<DeleteRowRequest> for pb::DeleteRowRequest {
+    fn from(x: DeleteRowRequest) -> pb::DeleteRowRequest {
+        pb::DeleteRowRequest{
+            table_name: x.table_name.into(),
+            primary_key: RowDeletion(x.row_key).to_pbuf(),
+            condition: x.condition.into(),
+            return_content: Some(x.in_return.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::DeleteRowResponse> for DeleteRowResponse {
+    type Error = Error;
+
+    fn try_from(x: pb::DeleteRowResponse) -> Result<DeleteRowResponse, Error> {
+        Ok(DeleteRowResponse{
+            base: super::BaseResponse::default(),
+            row: decode_returned_row(x.row)?,
+            consumed: x.consumed.into(),
+        })
+    }
+}
+
+impl From<DeleteRowRequest> for Bytes {
+    fn from(x: DeleteRowRequest) -> Bytes {
+        serialize_request::<DeleteRowRequest, pb::DeleteRowRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for DeleteRowResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        try_new_response::<Self, pb::DeleteRowResponse>(&v)
+    }
+}
+
+impl super::Request for DeleteRowRequest {
+    fn action(&self) -> Action {
+        Action::DeleteRow
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for DeleteRowResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+
+    fn consumed(&self) -> ConsumedCapacity {
+        self.consumed
+    }
+}
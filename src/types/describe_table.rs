@@ -0,0 +1,145 @@
+use bytes::Bytes;
+use crate::Error;
+use crate::protocol as pb;
+use crate::plainbuffer::PbufSerde;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct DescribeTableRequest {
+    pub name: Name,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TableStatus {
+    Active,
+    Inactive,
+    Loading,
+    Unloading,
+    Updating,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TableDescription {
+    pub table_meta: TableMeta,
+    pub reserved_throughput: ReservedThroughputDetails,
+    pub options: TableOptions,
+    pub status: TableStatus,
+    pub shard_splits: Vec<RowKey>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DescribeTableResponse {
+    pub base: super::BaseResponse,
+    pub description: TableDescription,
+}
+
+impl From<pb::TableStatus> for TableStatus {
+    fn from(x: pb::TableStatus) -> Self {
+        match x {
+            pb::TableStatus::ACTIVE => TableStatus::Active,
+            pb::TableStatus::INACTIVE => TableStatus::Inactive,
+            pb::TableStatus::LOADING => TableStatus::Loading,
+            pb::TableStatus::UNLOADING => TableStatus::Unloading,
+            pb::TableStatus::UPDATING => TableStatus::Updating,
+        }
+    }
+}
+
+impl From<DescribeTableRequest> for pb::DescribeTableRequest {
+    fn from(x: DescribeTableRequest) -> pb::DescribeTableRequest {
+        pb::DescribeTableRequest{
+            table_name: x.name.into(),
+        }
+    }
+}
+
+impl TryFrom<pb::DescribeTableResponse> for DescribeTableResponse {
+    type Error = Error;
+
+    fn try_from(x: pb::DescribeTableResponse) -> Result<DescribeTableResponse, Error> {
+        let options = TableOptions::from((
+            x.table_options,
+            x.reserved_throughput_details.capacity_unit.clone()));
+        let mut shard_splits = vec![];
+        for split in x.shard_splits.into_iter() {
+            shard_splits.push(RowKey::from_pbuf(Bytes::from(split))?);
+        }
+        Ok(DescribeTableResponse{
+            base: super::BaseResponse::default(),
+            description: TableDescription{
+                table_meta: x.table_meta.into(),
+                reserved_throughput: x.reserved_throughput_details.into(),
+                options,
+                status: x.table_status.into(),
+                shard_splits,
+            },
+        })
+    }
+}
+
+impl From<DescribeTableRequest> for Bytes {
+    fn from(x: DescribeTableRequest) -> Bytes {
+        serialize_request::<DescribeTableRequest, pb::DescribeTableRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for DescribeTableResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        try_new_response::<Self, pb::DescribeTableResponse>(&v)
+    }
+}
+
+impl super::Request for DescribeTableRequest {
+    fn action(&self) -> Action {
+        Action::DescribeTable
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for DescribeTableResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[quickcheck]
+    fn describetable_decodes_shard_splits(meta: TableMeta, splits: Vec<RowKey>) {
+        let pb_resp = pb::DescribeTableResponse{
+            table_meta: meta.clone().into(),
+            reserved_throughput_details: pb::ReservedThroughputDetails{
+                capacity_unit: pb::CapacityUnit{
+                    read: Some(1),
+                    write: Some(2),
+                },
+                last_increase_time: 1500000000,
+                last_decrease_time: None,
+            },
+            table_options: pb::TableOptions::default(),
+            table_status: pb::TableStatus::UPDATING,
+            shard_splits: splits.iter()
+                .map(|x| {
+                    x.to_pbuf()
+                })
+                .collect(),
+        };
+        let trial = DescribeTableResponse::try_from(pb_resp).unwrap().description;
+        assert_eq!(trial.table_meta, meta);
+        assert_eq!(trial.status, TableStatus::Updating);
+        assert_eq!(trial.shard_splits, splits);
+        assert_eq!(
+            trial.reserved_throughput.last_increase_time,
+            DateTime::from_millis(1500000000 * 1000));
+        assert_eq!(trial.reserved_throughput.last_decrease_time, None);
+        assert_eq!(trial.options.capacity_unit.write, SettableValue::Value(2));
+    }
+}
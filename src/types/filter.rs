@@ -0,0 +1,237 @@
+use bytes::Bytes;
+use crate::{Error, ErrorCode};
+use crate::protocol as pb;
+use crate::plainbuffer::SqlVariant;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Comparator {
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterEqual,
+    LessThan,
+    LessEqual,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ColumnValueFilter {
+    pub column: Name,
+    pub comparator: Comparator,
+    pub value: AttrValue,
+    pub pass_if_missing: bool,
+    pub latest_version_only: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Filter {
+    ColumnValue(ColumnValueFilter),
+    Not(Box<Filter>),
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+    ColumnPagination{
+        offset: i32,
+        limit: i32,
+    },
+}
+
+impl ColumnValueFilter {
+    pub fn new<T: ToString>(column: T, comparator: Comparator, value: AttrValue) -> Self {
+        Self{
+            column: Name::new(column),
+            comparator,
+            value,
+            pass_if_missing: true,
+            latest_version_only: true,
+        }
+    }
+}
+
+impl Filter {
+    pub fn column<T: ToString>(column: T, comparator: Comparator, value: AttrValue) -> Self {
+        Filter::ColumnValue(ColumnValueFilter::new(column, comparator, value))
+    }
+}
+
+impl std::ops::Not for Filter {
+    type Output = Filter;
+
+    fn not(self) -> Filter {
+        Filter::Not(Box::new(self))
+    }
+}
+
+impl From<ColumnValueFilter> for Filter {
+    fn from(x: ColumnValueFilter) -> Self {
+        Filter::ColumnValue(x)
+    }
+}
+
+impl From<Comparator> for pb::ComparatorType {
+    fn from(x: Comparator) -> Self {
+        match x {
+            Comparator::Equal => pb::ComparatorType::CT_EQUAL,
+            Comparator::NotEqual => pb::ComparatorType::CT_NOT_EQUAL,
+            Comparator::GreaterThan => pb::ComparatorType::CT_GREATER_THAN,
+            Comparator::GreaterEqual => pb::ComparatorType::CT_GREATER_EQUAL,
+            Comparator::LessThan => pb::ComparatorType::CT_LESS_THAN,
+            Comparator::LessEqual => pb::ComparatorType::CT_LESS_EQUAL,
+        }
+    }
+}
+
+impl From<pb::ComparatorType> for Comparator {
+    fn from(x: pb::ComparatorType) -> Self {
+        match x {
+            pb::ComparatorType::CT_EQUAL => Comparator::Equal,
+            pb::ComparatorType::CT_NOT_EQUAL => Comparator::NotEqual,
+            pb::ComparatorType::CT_GREATER_THAN => Comparator::GreaterThan,
+            pb::ComparatorType::CT_GREATER_EQUAL => Comparator::GreaterEqual,
+            pb::ComparatorType::CT_LESS_THAN => Comparator::LessThan,
+            pb::ComparatorType::CT_LESS_EQUAL => Comparator::LessEqual,
+        }
+    }
+}
+
+impl From<Filter> for pb::Filter {
+    fn from(x: Filter) -> Self {
+        match x {
+            Filter::ColumnValue(x) => pb::Filter{
+                type_pb: pb::FilterType::FT_SINGLE_COLUMN_VALUE,
+                filter: serialize_message(&pb::SingleColumnValueFilter{
+                    comparator: x.comparator.into(),
+                    column_name: x.column.into(),
+                    column_value: x.value.to_sql_variant(),
+                    filter_if_missing: !x.pass_if_missing,
+                    latest_version_only: x.latest_version_only,
+                }),
+            },
+            Filter::Not(x) => composite(pb::LogicalOperator::LO_NOT, vec![*x]),
+            Filter::And(xs) => composite(pb::LogicalOperator::LO_AND, xs),
+            Filter::Or(xs) => composite(pb::LogicalOperator::LO_OR, xs),
+            Filter::ColumnPagination{offset, limit} => pb::Filter{
+                type_pb: pb::FilterType::FT_COLUMN_PAGINATION,
+                filter: serialize_message(&pb::ColumnPaginationFilter{
+                    offset,
+                    limit,
+                }),
+            },
+        }
+    }
+}
+
+fn composite(combinator: pb::LogicalOperator, xs: Vec<Filter>) -> pb::Filter {
+    pb::Filter{
+        type_pb: pb::FilterType::FT_COMPOSITE_COLUMN_VALUE,
+        filter: serialize_message(&pb::CompositeColumnValueFilter{
+            combinator,
+            sub_filters: xs
+                .into_iter()
+                .map(|x| {
+                    x.into()
+                })
+                .collect(),
+        }),
+    }
+}
+
+impl TryFrom<pb::Filter> for Filter {
+    type Error = Error;
+
+    fn try_from(x: pb::Filter) -> Result<Filter, Error> {
+        match x.type_pb {
+            pb::FilterType::FT_SINGLE_COLUMN_VALUE => {
+                let x: pb::SingleColumnValueFilter = deserialize_message(&x.filter)?;
+                Ok(Filter::ColumnValue(ColumnValueFilter{
+                    column: Name::new(x.column_name),
+                    comparator: x.comparator.into(),
+                    value: AttrValue::from_sql_variant(Bytes::from(x.column_value))?,
+                    pass_if_missing: !x.filter_if_missing,
+                    latest_version_only: x.latest_version_only,
+                }))
+            }
+            pb::FilterType::FT_COMPOSITE_COLUMN_VALUE => {
+                let x: pb::CompositeColumnValueFilter = deserialize_message(&x.filter)?;
+                let mut subs = x.sub_filters
+                    .into_iter()
+                    .map(Filter::try_from)
+                    .collect::<Result<Vec<_>, Error>>()?;
+                match x.combinator {
+                    pb::LogicalOperator::LO_NOT => {
+                        if subs.len() != 1 {
+                            return Err(Error{
+                                code: ErrorCode::CorruptedResponse,
+                                message: format!("NOT filter requires exactly 1 sub-filter, but {} given.", subs.len()),
+                            });
+                        }
+                        Ok(!subs.pop().unwrap())
+                    }
+                    pb::LogicalOperator::LO_AND => Ok(Filter::And(subs)),
+                    pb::LogicalOperator::LO_OR => Ok(Filter::Or(subs)),
+                }
+            }
+            pb::FilterType::FT_COLUMN_PAGINATION => {
+                let x: pb::ColumnPaginationFilter = deserialize_message(&x.filter)?;
+                Ok(Filter::ColumnPagination{
+                    offset: x.offset,
+                    limit: x.limit,
+                })
+            }
+        }
+    }
+}
+
+pub(in crate::types) fn encode_filter(x: Filter) -> Vec<u8> {
+    serialize_message(&pb::Filter::from(x))
+}
+
+pub(in crate::types) fn decode_filter(buf: &[u8]) -> Result<Filter, Error> {
+    let x: pb::Filter = deserialize_message(buf)?;
+    Filter::try_from(x)
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn filter_roundtrip() {
+        let oracle = Filter::And(vec![
+            Filter::column("status", Comparator::Equal, AttrValue::Str("running".to_string())),
+            !Filter::from(ColumnValueFilter{
+                pass_if_missing: false,
+                latest_version_only: false,
+                ..ColumnValueFilter::new("retries", Comparator::GreaterEqual, AttrValue::Int(3))
+            }),
+            Filter::Or(vec![
+                Filter::column("ratio", Comparator::LessThan, AttrValue::Float(0.5)),
+                Filter::column("flag", Comparator::NotEqual, AttrValue::Bool(true)),
+            ]),
+        ]);
+        let buf = encode_filter(oracle.clone());
+        let trial = decode_filter(&buf).unwrap();
+        assert_eq!(trial, oracle);
+
+        let oracle = Filter::ColumnPagination{offset: 2, limit: 10};
+        let buf = encode_filter(oracle.clone());
+        let trial = decode_filter(&buf).unwrap();
+        assert_eq!(trial, oracle);
+    }
+
+    #[test]
+    fn filter_single_column_layout() {
+        let mut x = ColumnValueFilter::new("c", Comparator::LessEqual, AttrValue::Int(1));
+        x.pass_if_missing = false;
+        let trial = pb::Filter::from(Filter::from(x));
+        assert_eq!(trial.type_pb, pb::FilterType::FT_SINGLE_COLUMN_VALUE);
+        let trial: pb::SingleColumnValueFilter = deserialize_message(&trial.filter).unwrap();
+        assert_eq!(trial, pb::SingleColumnValueFilter{
+            comparator: pb::ComparatorType::CT_LESS_EQUAL,
+            column_name: "c".to_string(),
+            column_value: vec![0x0, 1, 0, 0, 0, 0, 0, 0, 0],
+            filter_if_missing: true,
+            latest_version_only: true,
+        });
+    }
+}
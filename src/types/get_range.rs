@@ -0,0 +1,159 @@
+use bytes::Bytes;
+use crate::Error;
+use crate::protocol as pb;
+use crate::plainbuffer::PbufSerde;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+impl From<Direction> for pb::Direction {
+    fn from(x: Direction) -> Self {
+        match x {
+            Direction::Forward => pb::Direction::FORWARD,
+            Direction::Backward => pb::Direction::BACKWARD,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetRangeRequest {
+    pub table_name: Name,
+    pub direction: Direction,
+    pub inclusive_start: ExtendedRowKey,
+    pub exclusive_end: ExtendedRowKey,
+    pub columns_to_get: Vec<Name>,
+    pub max_versions: Option<i32>,
+    pub time_range: Option<TimeRange>,
+    pub limit: Option<usize>, // total rows, not rows per response
+    pub start_column: Option<Name>, // inclusive
+    pub end_column: Option<Name>, // exclusive
+    pub token: Option<Bytes>,
+    pub filter: Option<Filter>,
+}
+
+impl GetRangeRequest {
+    pub fn new<T: ToString>(
+        table_name: T,
+        inclusive_start: ExtendedRowKey,
+        exclusive_end: ExtendedRowKey,
+    ) -> Result<Self, Error> {
+        Ok(Self{
+            table_name: Name::new(table_name),
+            direction: Direction::Forward,
+            inclusive_start,
+            exclusive_end,
+            columns_to_get: vec![],
+            max_versions: Some(1),
+            time_range: None,
+            limit: None,
+            start_column: None,
+            end_column: None,
+            token: None,
+            filter: None,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetRangeResponse {
+    pub base: super::BaseResponse,
+    pub rows: Vec<Row>,
+    pub next_start: Option<ExtendedRowKey>,
+    pub next_token: Option<Bytes>,
+    pub consumed: ConsumedCapacity,
+}
+
+impl From<GetRangeRequest> for pb::GetRangeRequest {
+    fn from(x: GetRangeRequest) -> pb::GetRangeRequest {
+        pb::GetRangeRequest{
+            table_name: x.table_name.into(),
+            direction: x.direction.into(),
+            columns_to_get: x.columns_to_get
+                .into_iter()
+                .map(|x| {
+                    x.into()
+                })
+                .collect(),
+            time_range: x.time_range.map(|x| x.into()),
+            max_versions: x.max_versions,
+            limit: x.limit.map(|x| {
+                std::cmp::min(x, i32::MAX as usize) as i32
+            }),
+            inclusive_start_primary_key: x.inclusive_start.to_pbuf(),
+            exclusive_end_primary_key: x.exclusive_end.to_pbuf(),
+            cache_blocks: true,
+            filter: x.filter.map(encode_filter),
+            start_column: x.start_column.map(|x| x.into()),
+            end_column: x.end_column.map(|x| x.into()),
+            token: x.token.map(|x| x.to_vec()),
+        }
+    }
+}
+
+impl TryFrom<pb::GetRangeResponse> for GetRangeResponse {
+    type Error = Error;
+
+    fn try_from(x: pb::GetRangeResponse) -> Result<GetRangeResponse, Error> {
+        let rows = if x.rows.is_empty() {
+            vec![]
+        } else {
+            Vec::<Row>::from_pbuf(Bytes::from(x.rows))?
+        };
+        let next_start = match x.next_start_primary_key {
+            Some(x) if !x.is_empty() => {
+                Some(ExtendedRowKey::from_pbuf(Bytes::from(x))?)
+            }
+            _ => None,
+        };
+        let next_token = match x.next_token {
+            Some(x) if !x.is_empty() => Some(Bytes::from(x)),
+            _ => None,
+        };
+        Ok(GetRangeResponse{
+            base: super::BaseResponse::default(),
+            rows,
+            next_start,
+            next_token,
+            consumed: x.consumed.into(),
+        })
+    }
+}
+
+impl From<GetRangeRequest> for Bytes {
+    fn from(x: GetRangeRequest) -> Bytes {
+        serialize_request::<GetRangeRequest, pb::GetRangeRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for GetRangeResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        try_new_response::<Self, pb::GetRangeResponse>(&v)
+    }
+}
+
+impl super::Request for GetRangeRequest {
+    fn action(&self) -> Action {
+        Action::GetRange
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for GetRangeResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+
+    fn consumed(&self) -> ConsumedCapacity {
+        self.consumed
+    }
+}
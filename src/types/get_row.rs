@@ -0,0 +1,121 @@
+use bytes::Bytes;
+use crate::Error;
+use crate::protocol as pb;
+use crate::plainbuffer::PbufSerde;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct GetRowRequest {
+    pub table_name: Name,
+    pub row_key: RowKey,
+    pub columns_to_get: Vec<Name>,
+    pub max_versions: Option<i32>,
+    pub time_range: Option<TimeRange>,
+    pub filter: Option<Filter>,
+    pub start_column: Option<Name>, // inclusive
+    pub end_column: Option<Name>, // exclusive
+    pub token: Option<Bytes>,
+}
+
+impl GetRowRequest {
+    pub fn new<T: ToString>(table_name: T, row_key: RowKey) -> Result<Self, Error> {
+        Ok(Self{
+            table_name: Name::new(table_name),
+            row_key,
+            columns_to_get: vec![],
+            max_versions: Some(1),
+            time_range: None,
+            filter: None,
+            start_column: None,
+            end_column: None,
+            token: None,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetRowResponse {
+    pub base: super::BaseResponse,
+    pub row: Option<Row>,
+    pub next_token: Option<Bytes>, // present iff columns of the row remain
+    pub consumed: ConsumedCapacity,
+}
+
+impl From<GetRowRequest> for pb::GetRowRequest {
+    fn from(x: GetRowRequest) -> pb::GetRowRequest {
+        pb::GetRowRequest{
+            table_name: x.table_name.into(),
+            primary_key: x.row_key.to_pbuf(),
+            columns_to_get: x.columns_to_get
+                .into_iter()
+                .map(|x| {
+                    x.into()
+                })
+                .collect(),
+            time_range: x.time_range.map(|x| x.into()),
+            max_versions: x.max_versions,
+            cache_blocks: true,
+            filter: x.filter.map(encode_filter),
+            start_column: x.start_column.map(|x| x.into()),
+            end_column: x.end_column.map(|x| x.into()),
+            token: x.token.map(|x| x.to_vec()),
+        }
+    }
+}
+
+impl TryFrom<pb::GetRowResponse> for GetRowResponse {
+    type Error = Error;
+
+    fn try_from(x: pb::GetRowResponse) -> Result<GetRowResponse, Error> {
+        let row = if x.row.is_empty() {
+            None
+        } else {
+            Some(Row::from_pbuf(Bytes::from(x.row))?)
+        };
+        let next_token = match x.next_token {
+            Some(x) if !x.is_empty() => Some(Bytes::from(x)),
+            _ => None,
+        };
+        Ok(GetRowResponse{
+            base: super::BaseResponse::default(),
+            row,
+            next_token,
+            consumed: x.consumed.into(),
+        })
+    }
+}
+
+impl From<GetRowRequest> for Bytes {
+    fn from(x: GetRowRequest) -> Bytes {
+        serialize_request::<GetRowRequest, pb::GetRowRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for GetRowResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        try_new_response::<Self, pb::GetRowResponse>(&v)
+    }
+}
+
+impl super::Request for GetRowRequest {
+    fn action(&self) -> Action {
+        Action::GetRow
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for GetRowResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+
+    fn consumed(&self) -> ConsumedCapacity {
+        self.consumed
+    }
+}
@@ -1,16 +1,22 @@
+use bytes::Bytes;
+use crate::Error;
 use crate::protocol as pb;
+use crate::plainbuffer::PbufSerde;
+use super::*;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum InReturn {
     Nothing,
     RowKey,
+    AfterModify(Vec<Name>), // values of these columns after increments
 }
 
-impl From<InReturn> for pb::ReturnType {
-    fn from(x: InReturn) -> Self {
+impl From<&InReturn> for pb::ReturnType {
+    fn from(x: &InReturn) -> Self {
         match x {
             InReturn::Nothing => pb::ReturnType::RT_NONE,
             InReturn::RowKey => pb::ReturnType::RT_PK,
+            InReturn::AfterModify(_) => pb::ReturnType::RT_AFTER_MODIFY,
         }
     }
 }
@@ -19,15 +25,40 @@ impl From<pb::ReturnType> for InReturn {
     fn from(x: pb::ReturnType) -> Self {
         match x {
             pb::ReturnType::RT_NONE => InReturn::Nothing,
-            pb::ReturnType::RT_PK => InReturn::Nothing,
+            pb::ReturnType::RT_PK => InReturn::RowKey,
+            pb::ReturnType::RT_AFTER_MODIFY => InReturn::AfterModify(vec![]),
         }
     }
 }
 
 impl From<InReturn> for pb::ReturnContent {
     fn from(x: InReturn) -> Self {
+        let return_type = Some(pb::ReturnType::from(&x));
+        let return_column_names = match x {
+            InReturn::AfterModify(names) => names
+                .into_iter()
+                .map(|x| {
+                    x.into()
+                })
+                .collect(),
+            _ => vec![],
+        };
         Self{
-            return_type: Some(x.into())
+            return_type,
+            return_column_names,
+        }
+    }
+}
+
+// decodes the returned row of write operations
+pub(in crate::types) fn decode_returned_row(
+    row: Option<Vec<u8>>,
+) -> Result<Option<Row>, Error> {
+    match row {
+        Some(row) if !row.is_empty() => {
+            let row = Row::from_pbuf(Bytes::from(row))?;
+            Ok(Some(row))
         }
+        _ => Ok(None),
     }
 }
@@ -0,0 +1,71 @@
+use bytes::Bytes;
+use crate::Error;
+use crate::protocol as pb;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct LoadTableRequest {
+    pub name: Name,
+    pub wait_until_active: Option<std::time::Duration>, // timeout
+}
+
+impl LoadTableRequest {
+    pub fn new<T: ToString>(name: T) -> Self {
+        Self{
+            name: Name::new(name),
+            wait_until_active: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadTableResponse {
+    pub base: super::BaseResponse,
+}
+
+impl From<LoadTableRequest> for pb::LoadTableRequest {
+    fn from(x: LoadTableRequest) -> pb::LoadTableRequest {
+        pb::LoadTableRequest{
+            table_name: x.name.into(),
+        }
+    }
+}
+
+impl From<pb::LoadTableResponse> for LoadTableResponse {
+    fn from(_: pb::LoadTableResponse) -> LoadTableResponse {
+        LoadTableResponse{
+            base: super::BaseResponse::default(),
+        }
+    }
+}
+
+impl From<LoadTableRequest> for Bytes {
+    fn from(x: LoadTableRequest) -> Bytes {
+        serialize_request::<LoadTableRequest, pb::LoadTableRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for LoadTableResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        new_response::<Self, pb::LoadTableResponse>(&v)
+    }
+}
+
+impl super::Request for LoadTableRequest {
+    fn action(&self) -> Action {
+        Action::LoadTable
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for LoadTableResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+}
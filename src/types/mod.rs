@@ -1,6 +1,7 @@
 use bytes::Bytes;
 use crate::Error;
 use quick_protobuf::{MessageRead, BytesReader, message::MessageWrite};
+use std::convert::TryFrom;
 
 pub trait Request {
     fn action(&self) -> Action;
@@ -10,6 +11,10 @@ pub trait Request {
 pub(crate) trait Response {
     fn base_mut_ref(&mut self) -> &mut BaseResponse;
 
+    fn consumed(&self) -> ConsumedCapacity {
+        ConsumedCapacity::default()
+    }
+
     fn reset_base(
         &mut self,
         server_tm: Option<chrono::DateTime<chrono::Utc>>,
@@ -20,6 +25,22 @@ pub(crate) trait Response {
     }
 }
 
+// batch requests, whose failed rows can be retried in a sub-request
+pub(crate) trait BatchRequest: Clone {
+    // keeps the order of tables and of rows
+    fn select(&self, positions: &[(usize, usize)]) -> Self;
+}
+
+// results and consumed capacities of rows in a table
+pub(crate) type BatchRowsMut<'a> = (&'a mut Vec<Result<Option<Row>, Error>>, &'a mut Vec<ConsumedCapacity>);
+
+pub(crate) trait BatchResponse {
+    type Request: BatchRequest;
+
+    fn check_against(&self, req: &Self::Request) -> Result<(), Error>;
+    fn tables_mut(&mut self) -> Vec<BatchRowsMut<'_>>;
+}
+
 pub(in crate::types) fn serialize_request<Req, PbReq>(
     x: Req,
 ) -> Bytes
@@ -28,13 +49,25 @@ where
     PbReq: From<Req> + MessageWrite
 {
     let req = PbReq::from(x);
-    let len = req.get_size();
+    Bytes::from(serialize_message(&req))
+}
+
+pub(crate) fn serialize_message<M: MessageWrite>(msg: &M) -> Vec<u8> {
+    let len = msg.get_size();
     let mut body = Vec::new();
     body.resize(len, 0u8);
     let writer = quick_protobuf::writer::BytesWriter::new(&mut body);
     let mut writer = quick_protobuf::writer::Writer::new(writer);
-    req.write_message(&mut writer).unwrap();
-    Bytes::from(body)
+    msg.write_message(&mut writer).unwrap();
+    body
+}
+
+pub(crate) fn deserialize_message<'a, M: MessageRead<'a>>(
+    b: &'a [u8],
+) -> Result<M, Error> {
+    let mut reader = BytesReader::from_bytes(b);
+    let msg = M::from_reader(&mut reader, b)?;
+    Ok(msg)
 }
 
 pub(in crate::types) fn new_response<'a, Resp, PbResp>(
@@ -49,6 +82,18 @@ where
     Ok(resp.into())
 }
 
+pub(in crate::types) fn try_new_response<'a, Resp, PbResp>(
+    b: &'a [u8],
+) -> Result<Resp, Error>
+where
+    PbResp: MessageRead<'a>,
+    Resp: TryFrom<PbResp, Error=Error> + std::marker::Sized,
+{
+    let mut reader = BytesReader::from_bytes(b);
+    let resp = PbResp::from_reader(&mut reader, b)?;
+    Resp::try_from(resp)
+}
+
 #[derive(Debug, Clone)]
 pub struct BaseResponse {
     pub server_timestamp: Option<chrono::DateTime<chrono::Utc>>,
@@ -66,6 +111,8 @@ impl Default for BaseResponse {
 
 mod common;
 pub use self::common::*;
+mod consumed;
+pub use self::consumed::*;
 mod action;
 pub use self::action::*;
 mod name;
@@ -80,6 +127,16 @@ mod create_table;
 pub use self::create_table::*;
 mod delete_table;
 pub use self::delete_table::*;
+mod describe_table;
+pub use self::describe_table::*;
+mod update_table;
+pub use self::update_table::*;
+mod load_table;
+pub use self::load_table::*;
+mod unload_table;
+pub use self::unload_table::*;
+mod compute_split_points;
+pub use self::compute_split_points::*;
 mod rowkey;
 pub use self::rowkey::*;
 mod attr;
@@ -88,6 +145,24 @@ mod row;
 pub use self::row::*;
 mod put_row;
 pub use self::put_row::*;
+mod get_row;
+pub use self::get_row::*;
+mod time_range;
+pub use self::time_range::*;
+mod row_change;
+pub use self::row_change::*;
+mod update_row;
+pub use self::update_row::*;
+mod delete_row;
+pub use self::delete_row::*;
+mod get_range;
+pub use self::get_range::*;
+mod batch_write_row;
+pub use self::batch_write_row::*;
+mod batch_get_row;
+pub use self::batch_get_row::*;
+mod filter;
+pub use self::filter::*;
 mod condition;
 pub use self::condition::*;
 mod in_return;
@@ -18,9 +18,7 @@ impl PutRowRequest {
         Ok(Self{
             table_name: Name::new(table_name),
             row,
-            condition: Condition{
-                row_exist: RowExistenceExpectation::Ignore,
-            },
+            condition: Condition::new(RowExistenceExpectation::Ignore),
             in_return: InReturn::Nothing,
         })
     }
@@ -29,6 +27,8 @@ impl PutRowRequest {
 #[derive(Debug, Clone)]
 pub struct PutRowResponse {
     pub base: super::BaseResponse,
+    pub row: Option<Row>, // present iff InReturn::RowKey or InReturn::AfterModify
+    pub consumed: ConsumedCapacity,
 }
 
 impl From<PutRowRequest> for pb::PutRowRequest {
@@ -42,11 +42,15 @@ impl From<PutRowRequest> for pb::PutRowRequest {
     }
 }
 
-impl From<pb::PutRowResponse> for PutRowResponse {
-    fn from(_: pb::PutRowResponse) -> PutRowResponse {
-        PutRowResponse{
-            base: super::BaseResponse::default()
-        }
+impl TryFrom<pb::PutRowResponse> for PutRowResponse {
+    type Error = Error;
+
+    fn try_from(x: pb::PutRowResponse) -> Result<PutRowResponse, Error> {
+        Ok(PutRowResponse{
+            base: super::BaseResponse::default(),
+            row: decode_returned_row(x.row)?,
+            consumed: x.consumed.into(),
+        })
     }
 }
 
@@ -60,7 +64,7 @@ impl TryFrom<Vec<u8>> for PutRowResponse {
     type Error = Error;
 
     fn try_from(v: Vec<u8>) -> Result<Self, Error> {
-        new_response::<Self, pb::PutRowResponse>(&v)
+        try_new_response::<Self, pb::PutRowResponse>(&v)
     }
 }
 
@@ -78,4 +82,8 @@ impl super::Response for PutRowResponse {
     fn base_mut_ref(&mut self) -> &mut BaseResponse {
         &mut self.base
     }
+
+    fn consumed(&self) -> ConsumedCapacity {
+        self.consumed
+    }
 }
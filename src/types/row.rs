@@ -9,6 +9,29 @@ pub struct Row {
     pub attrs: Vec<Attribute>,
 }
 
+impl Row {
+    // Groups attributes by column, in the order of first appearance.
+    // Versions of a column are sorted from the oldest to the newest.
+    pub fn versions(&self) -> Vec<(Name, Vec<Attribute>)> {
+        let mut res: Vec<(Name, Vec<Attribute>)> = vec![];
+        for attr in self.attrs.iter() {
+            match res.iter_mut().find(|(name, _)| name == &attr.name) {
+                Some((_, versions)) => versions.push(attr.clone()),
+                None => res.push((attr.name.clone(), vec![attr.clone()])),
+            }
+        }
+        for (_, versions) in res.iter_mut() {
+            versions.sort_by_key(|x| {
+                match &x.timestamp {
+                    AttrTimestamp::ClientAttach(tm) => (false, Some(tm.clone())),
+                    AttrTimestamp::ServerAttach => (true, None), // not yet assigned
+                }
+            });
+        }
+        res
+    }
+}
+
 #[cfg(test)]
 impl Arbitrary for Row {
     fn arbitrary<G: Gen>(g: &mut G) -> Self {
@@ -39,3 +62,39 @@ impl Arbitrary for Row {
         Box::new(res.chain(xs))
     }
 }
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn row_versions_grouped_by_column() {
+        let attr = |name: &str, value: i64, ts: AttrTimestamp| {
+            Attribute{
+                name: Name::new(name),
+                value: AttrValue::Int(value),
+                timestamp: ts,
+            }
+        };
+        let at = |millis| AttrTimestamp::ClientAttach(DateTime::from_millis(millis));
+        let row = Row{
+            row_key: RowKey::new(vec![]),
+            attrs: vec![
+                attr("b", 3, at(3000)),
+                attr("a", 2, at(2000)),
+                attr("b", 1, at(1000)),
+                attr("b", 9, AttrTimestamp::ServerAttach),
+            ],
+        };
+        let trial = row.versions();
+        let oracle = vec![
+            (Name::new("b"), vec![
+                attr("b", 1, at(1000)),
+                attr("b", 3, at(3000)),
+                attr("b", 9, AttrTimestamp::ServerAttach),
+            ]),
+            (Name::new("a"), vec![attr("a", 2, at(2000))]),
+        ];
+        assert_eq!(trial, oracle);
+    }
+}
@@ -0,0 +1,128 @@
+use super::*;
+
+#[cfg(test)]
+use quickcheck::{Arbitrary, Gen};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RowChange {
+    pub row_key: RowKey,
+    pub cells: Vec<CellChange>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CellChange {
+    Put(Attribute),
+    DeleteOneVersion(Name, DateTime),
+    DeleteAllVersions(Name),
+    Increment(Name, i64), // by delta
+}
+
+impl RowChange {
+    pub fn new(row_key: RowKey) -> Self {
+        Self{
+            row_key,
+            cells: vec![],
+        }
+    }
+
+    pub fn put(mut self, attr: Attribute) -> Self {
+        self.cells.push(CellChange::Put(attr));
+        self
+    }
+
+    pub fn delete_one_version<T: ToString>(mut self, name: T, timestamp: DateTime) -> Self {
+        self.cells.push(CellChange::DeleteOneVersion(Name::new(name), timestamp));
+        self
+    }
+
+    pub fn delete_all_versions<T: ToString>(mut self, name: T) -> Self {
+        self.cells.push(CellChange::DeleteAllVersions(Name::new(name)));
+        self
+    }
+
+    pub fn increment<T: ToString>(mut self, name: T, delta: i64) -> Self {
+        self.cells.push(CellChange::Increment(Name::new(name), delta));
+        self
+    }
+}
+
+impl CellChange {
+    pub fn name(&self) -> &Name {
+        match self {
+            CellChange::Put(attr) => &attr.name,
+            CellChange::DeleteOneVersion(name, _) => name,
+            CellChange::DeleteAllVersions(name) => name,
+            CellChange::Increment(name, _) => name,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Arbitrary for RowChange {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        RowChange{
+            row_key: RowKey::arbitrary(g),
+            cells: Vec::<CellChange>::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let row_key = self.row_key.clone();
+        let res = self.cells.shrink()
+            .map(move |x| {
+                RowChange{
+                    row_key: row_key.clone(),
+                    cells: x,
+                }
+            });
+        Box::new(res)
+    }
+}
+
+#[cfg(test)]
+impl Arbitrary for CellChange {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        match g.next_u32() % 4 {
+            0 => CellChange::Put(Attribute::arbitrary(g)),
+            1 => CellChange::DeleteOneVersion(Name::arbitrary(g), DateTime::now()),
+            2 => CellChange::DeleteAllVersions(Name::arbitrary(g)),
+            3 => CellChange::Increment(Name::arbitrary(g), i64::arbitrary(g)),
+            _ => unimplemented!()
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        match self {
+            CellChange::Put(attr) => {
+                let res = attr.shrink()
+                    .map(|x| {
+                        CellChange::Put(x)
+                    });
+                Box::new(res)
+            }
+            CellChange::DeleteOneVersion(name, tm) => {
+                let tm = tm.clone();
+                let res = name.shrink()
+                    .map(move |x| {
+                        CellChange::DeleteOneVersion(x, tm.clone())
+                    });
+                Box::new(res)
+            }
+            CellChange::DeleteAllVersions(name) => {
+                let res = name.shrink()
+                    .map(|x| {
+                        CellChange::DeleteAllVersions(x)
+                    });
+                Box::new(res)
+            }
+            CellChange::Increment(name, delta) => {
+                let delta = *delta;
+                let res = name.shrink()
+                    .map(move |x| {
+                        CellChange::Increment(x, delta)
+                    });
+                Box::new(res)
+            }
+        }
+    }
+}
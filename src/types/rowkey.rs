@@ -1,5 +1,6 @@
 use bytes::Bytes;
 use crate::{Error, ErrorCode};
+use std::cmp::Ordering;
 use std::convert::TryFrom;
 use super::*;
 
@@ -29,6 +30,7 @@ pub enum RowKeyValue {
     Int(i64),
     Str(String),
     Blob(Bytes),
+    AutoIncr, // only in PutRow, where the server assigns the value
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -69,6 +71,34 @@ impl ExtendedRowKey {
     }
 }
 
+impl ExtendedRowKey {
+    // None if types of values mismatch, or AutoIncr is involved
+    pub(crate) fn compare(&self, other: &ExtendedRowKey) -> Option<Ordering> {
+        for (x, y) in self.iter().zip(other.iter()) {
+            match x.value.compare(&y.value)? {
+                Ordering::Equal => {}
+                ord => return Some(ord),
+            }
+        }
+        Some(self.0.len().cmp(&other.0.len()))
+    }
+}
+
+impl ExtendedRowKeyValue {
+    pub(in crate::types) fn compare(&self, other: &ExtendedRowKeyValue) -> Option<Ordering> {
+        match (self, other) {
+            (ExtendedRowKeyValue::InfMin, ExtendedRowKeyValue::InfMin) => Some(Ordering::Equal),
+            (ExtendedRowKeyValue::InfMax, ExtendedRowKeyValue::InfMax) => Some(Ordering::Equal),
+            (ExtendedRowKeyValue::InfMin, _) | (_, ExtendedRowKeyValue::InfMax) => Some(Ordering::Less),
+            (ExtendedRowKeyValue::InfMax, _) | (_, ExtendedRowKeyValue::InfMin) => Some(Ordering::Greater),
+            (ExtendedRowKeyValue::Int(x), ExtendedRowKeyValue::Int(y)) => Some(x.cmp(y)),
+            (ExtendedRowKeyValue::Str(x), ExtendedRowKeyValue::Str(y)) => Some(x.cmp(y)),
+            (ExtendedRowKeyValue::Blob(x), ExtendedRowKeyValue::Blob(y)) => Some(x.cmp(y)),
+            _ => None,
+        }
+    }
+}
+
 impl From<RowKey> for ExtendedRowKey {
     fn from(rk: RowKey) -> Self {
         let ext_rk = rk.into_iter()
@@ -130,6 +160,7 @@ impl From<RowKeyValue> for ExtendedRowKeyValue {
             RowKeyValue::Int(x) => ExtendedRowKeyValue::Int(x),
             RowKeyValue::Str(x) => ExtendedRowKeyValue::Str(x),
             RowKeyValue::Blob(x) => ExtendedRowKeyValue::Blob(x),
+            RowKeyValue::AutoIncr => ExtendedRowKeyValue::AutoIncr,
         }
     }
 }
@@ -138,11 +169,12 @@ impl TryFrom<ExtendedRowKeyValue> for RowKeyValue {
     type Error = Error;
 
     fn try_from(value: ExtendedRowKeyValue) -> Result<Self, Self::Error> {
-        let msg = "Cannot convert InfMin/InfMax/AutoIncr to PrimaryKeyValue";
+        let msg = "Cannot convert InfMin/InfMax to PrimaryKeyValue";
         match value {
             ExtendedRowKeyValue::Int(x) => Ok(RowKeyValue::Int(x)),
             ExtendedRowKeyValue::Str(x) => Ok(RowKeyValue::Str(x)),
             ExtendedRowKeyValue::Blob(x) => Ok(RowKeyValue::Blob(x)),
+            ExtendedRowKeyValue::AutoIncr => Ok(RowKeyValue::AutoIncr),
             _ => Err(Error{
                 code: ErrorCode::ClientUnknown,
                 message: msg.to_string(),
@@ -350,3 +382,38 @@ impl Arbitrary for ExtendedRowKey {
         res
     }
 }
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    fn key(xs: Vec<ExtendedRowKeyValue>) -> ExtendedRowKey {
+        ExtendedRowKey::new(xs
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                ExtendedRowKeyColumn{
+                    name: Name::new(format!("pk{}", i)),
+                    value,
+                }
+            })
+            .collect())
+    }
+
+    #[test]
+    fn extendedrowkey_compare() {
+        use ExtendedRowKeyValue::*;
+        let cases = vec![
+            (vec![Int(1), InfMin], vec![Int(1), Str("a".to_string())], Some(Ordering::Less)),
+            (vec![Int(1), InfMax], vec![Int(2), InfMin], Some(Ordering::Less)),
+            (vec![Str("b".to_string())], vec![Str("ab".to_string())], Some(Ordering::Greater)),
+            (vec![Blob(Bytes::from_static(b"a")), InfMin], vec![Blob(Bytes::from_static(b"a")), InfMin], Some(Ordering::Equal)),
+            (vec![InfMax], vec![InfMin], Some(Ordering::Greater)),
+            (vec![Int(1)], vec![Str("1".to_string())], None),
+            (vec![AutoIncr], vec![AutoIncr], None),
+        ];
+        for (x, y, oracle) in cases.into_iter() {
+            assert_eq!(key(x.clone()).compare(&key(y.clone())), oracle, "{:?} vs {:?}", x, y);
+        }
+    }
+}
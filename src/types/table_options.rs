@@ -1,6 +1,6 @@
 use crate::protocol as pb;
 use std::convert::From;
-use super::common::SettableValue;
+use super::common::{SettableValue, DateTime};
 
 #[cfg(test)]
 use quickcheck::{Arbitrary, Gen};
@@ -11,6 +11,15 @@ pub struct TableOptions {
     pub time_to_live: SettableValue<chrono::Duration>,
     pub max_versions: SettableValue<i32>,
     pub deviated_duration: SettableValue<chrono::Duration>,
+    pub bloom_filter_type: SettableValue<BloomFilterType>,
+    pub block_size: SettableValue<i32>, // in bytes
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BloomFilterType {
+    None,
+    Cell,
+    Row,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -19,6 +28,13 @@ pub struct CapacityUnit {
     pub write: SettableValue<i32>,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReservedThroughputDetails {
+    pub capacity_unit: CapacityUnit,
+    pub last_increase_time: DateTime,
+    pub last_decrease_time: Option<DateTime>,
+}
+
 impl TableOptions {
     pub fn default_for_create() -> Self {
         Self{
@@ -26,6 +42,8 @@ impl TableOptions {
             time_to_live: SettableValue::Default,
             max_versions: SettableValue::Default,
             deviated_duration: SettableValue::Default,
+            bloom_filter_type: SettableValue::Default,
+            block_size: SettableValue::Default,
         }
     }
 
@@ -35,6 +53,8 @@ impl TableOptions {
             time_to_live: SettableValue::NoChange,
             max_versions: SettableValue::NoChange,
             deviated_duration: SettableValue::NoChange,
+            bloom_filter_type: SettableValue::NoChange,
+            block_size: SettableValue::NoChange,
         }
     }
 }
@@ -55,6 +75,26 @@ impl CapacityUnit {
     }
 }
 
+impl From<BloomFilterType> for pb::BloomFilterType {
+    fn from(x: BloomFilterType) -> pb::BloomFilterType {
+        match x {
+            BloomFilterType::None => pb::BloomFilterType::NONE,
+            BloomFilterType::Cell => pb::BloomFilterType::CELL,
+            BloomFilterType::Row => pb::BloomFilterType::ROW,
+        }
+    }
+}
+
+impl From<pb::BloomFilterType> for BloomFilterType {
+    fn from(x: pb::BloomFilterType) -> BloomFilterType {
+        match x {
+            pb::BloomFilterType::NONE => BloomFilterType::None,
+            pb::BloomFilterType::CELL => BloomFilterType::Cell,
+            pb::BloomFilterType::ROW => BloomFilterType::Row,
+        }
+    }
+}
+
 impl From<TableOptions> for (pb::TableOptions, pb::CapacityUnit) {
     fn from(x: TableOptions) -> Self {
         let proto_opts = {
@@ -72,11 +112,20 @@ impl From<TableOptions> for (pb::TableOptions, pb::CapacityUnit) {
                 SettableValue::Value(dd) => Some(dd.num_seconds()),
                 _ => None,
             };
+            // defaults of the following are left to the server
+            let bloom_filter_type = match x.bloom_filter_type {
+                SettableValue::Value(bf) => Some(bf.into()),
+                _ => None,
+            };
+            let block_size = match x.block_size {
+                SettableValue::Value(bs) => Some(bs),
+                _ => None,
+            };
             pb::TableOptions{
                 time_to_live,
                 max_versions,
-                bloom_filter_type: None,
-                block_size: None,
+                bloom_filter_type,
+                block_size,
                 deviation_cell_version_in_sec,
             }
         };
@@ -118,6 +167,14 @@ impl From<(pb::TableOptions, pb::CapacityUnit)> for TableOptions {
             Some(x) => SettableValue::Value(chrono::Duration::seconds(x)),
             None => SettableValue::Default,
         };
+        let bloom_filter_type = match x.0.bloom_filter_type {
+            Some(x) => SettableValue::Value(x.into()),
+            None => SettableValue::Default,
+        };
+        let block_size = match x.0.block_size {
+            Some(x) => SettableValue::Value(x),
+            None => SettableValue::Default,
+        };
         let capacity_unit = {
             let read = match x.1.read {
                 Some(x) if x > 0 => SettableValue::Value(x),
@@ -139,6 +196,39 @@ impl From<(pb::TableOptions, pb::CapacityUnit)> for TableOptions {
             time_to_live,
             max_versions,
             deviated_duration,
+            bloom_filter_type,
+            block_size,
+        }
+    }
+}
+
+impl From<pb::CapacityUnit> for CapacityUnit {
+    fn from(x: pb::CapacityUnit) -> Self {
+        let read = match x.read {
+            Some(x) => SettableValue::Value(x),
+            None => SettableValue::NoChange,
+        };
+        let write = match x.write {
+            Some(x) => SettableValue::Value(x),
+            None => SettableValue::NoChange,
+        };
+        CapacityUnit{
+            read,
+            write,
+        }
+    }
+}
+
+impl From<pb::ReservedThroughputDetails> for ReservedThroughputDetails {
+    fn from(x: pb::ReservedThroughputDetails) -> Self {
+        // times are in seconds since epoch
+        ReservedThroughputDetails{
+            capacity_unit: x.capacity_unit.into(),
+            last_increase_time: DateTime::from_millis(x.last_increase_time * 1000),
+            last_decrease_time: x.last_decrease_time
+                .map(|x| {
+                    DateTime::from_millis(x * 1000)
+                }),
         }
     }
 }
@@ -161,11 +251,31 @@ impl Arbitrary for TableOptions {
             let dd = dd as i64;
             SettableValue::Value(chrono::Duration::seconds(dd + 1))
         };
+        let bloom_filter_type = SettableValue::Value(BloomFilterType::arbitrary(g));
+        let block_size = {
+            let bs = u16::arbitrary(g);
+            let bs = bs as i32;
+            SettableValue::Value(bs + 1)
+        };
         Self{
             capacity_unit: CapacityUnit::arbitrary(g),
             time_to_live,
             max_versions,
             deviated_duration,
+            bloom_filter_type,
+            block_size,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Arbitrary for BloomFilterType {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        match g.next_u32() % 3 {
+            0 => BloomFilterType::None,
+            1 => BloomFilterType::Cell,
+            2 => BloomFilterType::Row,
+            _ => unreachable!(),
         }
     }
 }
@@ -277,9 +387,13 @@ mod ut {
                 time_to_live: ttl,
                 max_versions: mv,
                 deviated_duration: dd,
+                bloom_filter_type: SettableValue::NoChange,
+                block_size: SettableValue::NoChange,
             };
             type TOCU = (pb::TableOptions, pb::CapacityUnit);
             let (trial_opts, trial_cu) = TOCU::from(oracle.clone());
+            assert!(trial_opts.bloom_filter_type.is_none());
+            assert!(trial_opts.block_size.is_none());
             
             if let SettableValue::Value(ttl) = oracle.time_to_live {
                 assert!(trial_opts.time_to_live.is_some(),
@@ -401,9 +515,13 @@ mod ut {
                 time_to_live: ttl,
                 max_versions: mv,
                 deviated_duration: dd,
+                bloom_filter_type: SettableValue::Default,
+                block_size: SettableValue::Default,
             };
             type TOCU = (pb::TableOptions, pb::CapacityUnit);
             let (trial_opts, trial_cu) = TOCU::from(oracle.clone());
+            assert!(trial_opts.bloom_filter_type.is_none());
+            assert!(trial_opts.block_size.is_none());
 
             if let SettableValue::Value(ttl) = oracle.time_to_live {
                 let o_ttl = ttl.num_seconds();
@@ -0,0 +1,35 @@
+use crate::protocol as pb;
+use super::*;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TimeRange {
+    Range(DateTime, DateTime), // [start, end)
+    Specific(DateTime),
+}
+
+impl TimeRange {
+    pub fn range<T: Into<DateTime>>(start: T, end: T) -> Self {
+        TimeRange::Range(start.into(), end.into())
+    }
+
+    pub fn specific<T: Into<DateTime>>(tm: T) -> Self {
+        TimeRange::Specific(tm.into())
+    }
+}
+
+impl From<TimeRange> for pb::TimeRange {
+    fn from(x: TimeRange) -> Self {
+        match x {
+            TimeRange::Range(start, end) => pb::TimeRange{
+                start_time: Some(start.to_millis()),
+                end_time: Some(end.to_millis()),
+                specific_time: None,
+            },
+            TimeRange::Specific(tm) => pb::TimeRange{
+                start_time: None,
+                end_time: None,
+                specific_time: Some(tm.to_millis()),
+            },
+        }
+    }
+}
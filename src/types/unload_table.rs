@@ -0,0 +1,71 @@
+use bytes::Bytes;
+use crate::Error;
+use crate::protocol as pb;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct UnloadTableRequest {
+    pub name: Name,
+    pub wait_until_inactive: Option<std::time::Duration>, // timeout
+}
+
+impl UnloadTableRequest {
+    pub fn new<T: ToString>(name: T) -> Self {
+        Self{
+            name: Name::new(name),
+            wait_until_inactive: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UnloadTableResponse {
+    pub base: super::BaseResponse,
+}
+
+impl From<UnloadTableRequest> for pb::UnloadTableRequest {
+    fn from(x: UnloadTableRequest) -> pb::UnloadTableRequest {
+        pb::UnloadTableRequest{
+            table_name: x.name.into(),
+        }
+    }
+}
+
+impl From<pb::UnloadTableResponse> for UnloadTableResponse {
+    fn from(_: pb::UnloadTableResponse) -> UnloadTableResponse {
+        UnloadTableResponse{
+            base: super::BaseResponse::default(),
+        }
+    }
+}
+
+impl From<UnloadTableRequest> for Bytes {
+    fn from(x: UnloadTableRequest) -> Bytes {
+        serialize_request::<UnloadTableRequest, pb::UnloadTableRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for UnloadTableResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        new_response::<Self, pb::UnloadTableResponse>(&v)
+    }
+}
+
+impl super::Request for UnloadTableRequest {
+    fn action(&self) -> Action {
+        Action::UnloadTable
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for UnloadTableResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+}
@@ -0,0 +1,89 @@
+use bytes::Bytes;
+use crate::Error;
+use crate::protocol as pb;
+use crate::plainbuffer::PbufSerde;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct UpdateRowRequest {
+    pub table_name: Name,
+    pub row_change: RowChange,
+    pub condition: Condition,
+    pub in_return: InReturn,
+}
+
+impl UpdateRowRequest {
+    pub fn new<T: ToString>(table_name: T, row_change: RowChange) -> Result<Self, Error> {
+        Ok(Self{
+            table_name: Name::new(table_name),
+            row_change,
+            condition: Condition::new(RowExistenceExpectation::Ignore),
+            in_return: InReturn::Nothing,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateRowResponse {
+    pub base: super::BaseResponse,
+    pub row: Option<Row>, // present iff InReturn::RowKey or InReturn::AfterModify
+    pub consumed: ConsumedCapacity,
+}
+
+impl From<UpdateRowRequest> for pb::UpdateRowRequest {
+    fn from(x: UpdateRowRequest) -> pb::UpdateRowRequest {
+        pb::UpdateRowRequest{
+            table_name: x.table_name.into(),
+            row_change: x.row_change.to_pbuf(),
+            condition: x.condition.into(),
+            return_content: Some(x.in_return.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::UpdateRowResponse> for UpdateRowResponse {
+    type Error = Error;
+
+    fn try_from(x: pb::UpdateRowResponse) -> Result<UpdateRowResponse, Error> {
+        Ok(UpdateRowResponse{
+            base: super::BaseResponse::default(),
+            row: decode_returned_row(x.row)?,
+            consumed: x.consumed.into(),
+        })
+    }
+}
+
+impl From<UpdateRowRequest> for Bytes {
+    fn from(x: UpdateRowRequest) -> Bytes {
+        serialize_request::<UpdateRowRequest, pb::UpdateRowRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for UpdateRowResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        try_new_response::<Self, pb::UpdateRowResponse>(&v)
+    }
+}
+
+impl super::Request for UpdateRowRequest {
+    fn action(&self) -> Action {
+        Action::UpdateRow
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for UpdateRowResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+
+    fn consumed(&self) -> ConsumedCapacity {
+        self.consumed
+    }
+}
@@ -0,0 +1,119 @@
+use bytes::Bytes;
+use crate::Error;
+use crate::protocol as pb;
+use std::convert::TryFrom;
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct UpdateTableRequest {
+    pub name: Name,
+    pub options: TableOptions,
+}
+
+impl UpdateTableRequest {
+    pub fn new<T: ToString>(name: T) -> Self {
+        Self{
+            name: Name::new(name),
+            options: TableOptions::default_for_update(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateTableResponse {
+    pub base: super::BaseResponse,
+    pub reserved_throughput: ReservedThroughputDetails,
+    pub options: TableOptions,
+}
+
+impl From<UpdateTableRequest> for pb::UpdateTableRequest {
+    fn from(x: UpdateTableRequest) -> pb::UpdateTableRequest {
+        let (opts, cu) = x.options.into();
+        let reserved_throughput = if cu.read.is_some() || cu.write.is_some() {
+            Some(pb::ReservedThroughput{
+                capacity_unit: cu,
+            })
+        } else {
+            None
+        };
+        let table_options = if opts == pb::TableOptions::default() {
+            None
+        } else {
+            Some(opts)
+        };
+        pb::UpdateTableRequest{
+            table_name: x.name.into(),
+            reserved_throughput,
+            table_options,
+        }
+    }
+}
+
+impl From<pb::UpdateTableResponse> for UpdateTableResponse {
+    fn from(x: pb::UpdateTableResponse) -> UpdateTableResponse {
+        let options = TableOptions::from((
+            x.table_options,
+            x.reserved_throughput_details.capacity_unit.clone()));
+        UpdateTableResponse{
+            base: super::BaseResponse::default(),
+            reserved_throughput: x.reserved_throughput_details.into(),
+            options,
+        }
+    }
+}
+
+impl From<UpdateTableRequest> for Bytes {
+    fn from(x: UpdateTableRequest) -> Bytes {
+        serialize_request::<UpdateTableRequest, pb::UpdateTableRequest>(x)
+    }
+}
+
+impl TryFrom<Vec<u8>> for UpdateTableResponse {
+    type Error = Error;
+
+    fn try_from(v: Vec<u8>) -> Result<Self, Error> {
+        new_response::<Self, pb::UpdateTableResponse>(&v)
+    }
+}
+
+impl super::Request for UpdateTableRequest {
+    fn action(&self) -> Action {
+        Action::UpdateTable
+    }
+
+    fn path(&self) -> String {
+        self.action().to_string()
+    }
+}
+
+impl super::Response for UpdateTableResponse {
+    fn base_mut_ref(&mut self) -> &mut BaseResponse {
+        &mut self.base
+    }
+}
+
+#[cfg(test)]
+mod ut {
+    use super::*;
+
+    #[test]
+    fn updatetable_omits_unchanged_parts() {
+        let req = UpdateTableRequest::new("t0");
+        let trial = pb::UpdateTableRequest::from(req);
+        assert_eq!(trial.reserved_throughput, None);
+        assert_eq!(trial.table_options, None);
+
+        let mut req = UpdateTableRequest::new("t0");
+        req.options.max_versions = SettableValue::Value(3);
+        let trial = pb::UpdateTableRequest::from(req);
+        assert_eq!(trial.reserved_throughput, None);
+        assert_eq!(trial.table_options.unwrap().max_versions, Some(3));
+
+        let mut req = UpdateTableRequest::new("t0");
+        req.options.capacity_unit.read = SettableValue::Value(1);
+        let trial = pb::UpdateTableRequest::from(req);
+        let cu = trial.reserved_throughput.unwrap().capacity_unit;
+        assert_eq!((cu.read, cu.write), (Some(1), None));
+        assert_eq!(trial.table_options, None);
+    }
+}
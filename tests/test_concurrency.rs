@@ -17,3 +17,27 @@ async fn concurrency() -> Result<(), ots::Error> {
     assert!(r2.is_err());
     Ok(())
 }
+
+#[tokio::test]
+async fn concurrency_wait_queue() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions{
+        concurrency: 2,
+        on_concurrency_exhausted: ots::OnConcurrencyExhausted::Wait{
+            max_waiting: 1,
+            timeout: None,
+        },
+        ..Default::default()
+    };
+    let client = ots::Client::new(ep, cred, opts)?;
+    let x0 = client.list_table();
+    let x1 = client.list_table();
+    let x2 = client.list_table();
+    let x3 = client.list_table();
+    let (r0, r1, r2, r3) = tokio::join!(x0, x1, x2, x3);
+    assert!(r0.is_ok());
+    assert!(r1.is_ok());
+    assert!(r2.is_ok());
+    assert!(r3.is_err());
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use tablestore as ots;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+// a server which responds immediately, and records the peer of each connection
+fn serve(http2_only: bool) -> (ots::Endpoint, Arc<Mutex<Vec<SocketAddr>>>, Arc<AtomicUsize>) {
+    let peers = Arc::new(Mutex::new(vec![]));
+    let requests = Arc::new(AtomicUsize::new(0));
+    let make_svc = {
+        let peers = peers.clone();
+        let requests = requests.clone();
+        hyper::service::make_service_fn(move |conn: &hyper::server::conn::AddrStream| {
+            peers.lock().unwrap().push(conn.remote_addr());
+            let requests = requests.clone();
+            async move {
+                Ok::<_, Infallible>(hyper::service::service_fn(move |req: hyper::Request<hyper::Body>| {
+                    requests.fetch_add(1, Ordering::Relaxed);
+                    async move {
+                        let _ = hyper::body::to_bytes(req.into_body()).await;
+                        Ok::<_, Infallible>(hyper::Response::new(hyper::Body::empty()))
+                    }
+                }))
+            }
+        })
+    };
+    let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into())
+        .http2_only(http2_only)
+        .serve(make_svc);
+    let addr = server.local_addr();
+    tokio::spawn(server);
+    let ep = ots::Endpoint::new(format!("http://{}", addr), "local").unwrap();
+    (ep, peers, requests)
+}
+
+fn credential() -> ots::Credential {
+    ots::Credential::new("id", "secret").unwrap()
+}
+
+#[tokio::test]
+async fn pooled_connections() -> Result<(), ots::Error> {
+    let (ep, peers, _) = serve(false);
+    let client = ots::Client::new(ep, credential(), ots::ClientOptions::default())?;
+    for _ in 0..3usize {
+        client.list_table().await?;
+    }
+    assert_eq!(peers.lock().unwrap().len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_pooled_connections() -> Result<(), ots::Error> {
+    let (ep, peers, _) = serve(false);
+    let opts = ots::ClientOptions{
+        pool_max_idle_per_host: 0,
+        tcp_nodelay: true,
+        tcp_keepalive: Some(std::time::Duration::from_secs(30)),
+        ..Default::default()
+    };
+    let client = ots::Client::new(ep, credential(), opts)?;
+    for _ in 0..3usize {
+        client.list_table().await?;
+    }
+    assert_eq!(peers.lock().unwrap().len(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn http2_prior_knowledge() -> Result<(), ots::Error> {
+    let (ep, peers, requests) = serve(true);
+    let opts = ots::ClientOptions{
+        http_version: ots::HttpVersion::Http2,
+        ..Default::default()
+    };
+    let client = ots::Client::new(ep, credential(), opts)?;
+    let (r0, r1) = tokio::join!(client.list_table(), client.list_table());
+    r0?;
+    r1?;
+    assert_eq!(peers.lock().unwrap().len(), 1); // multiplexed
+    assert_eq!(requests.load(Ordering::Relaxed), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn bind_local_address() -> Result<(), ots::Error> {
+    let (ep, peers, _) = serve(false);
+    let opts = ots::ClientOptions{
+        local_address: Some([127, 0, 0, 1].into()),
+        ..Default::default()
+    };
+    let client = ots::Client::new(ep, credential(), opts)?;
+    client.list_table().await?;
+    let peers = peers.lock().unwrap();
+    assert_eq!(peers.len(), 1);
+    assert_eq!(peers[0].ip(), std::net::IpAddr::from([127, 0, 0, 1]));
+    Ok(())
+}
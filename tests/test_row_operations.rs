@@ -0,0 +1,754 @@
+use tablestore as ots;
+mod utils;
+use utils::*;
+use tokio::stream::StreamExt;
+
+#[tokio::test]
+async fn put_get() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "put_get".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = |x: &str| {
+        ots::RowKey::new(vec![ots::RowKeyColumn{
+            name: ots::Name::new("pkey"),
+            value: ots::RowKeyValue::Str(x.to_string()),
+        }])
+    };
+    let oracle = ots::Row{
+        row_key: row_key("exist"),
+        attrs: vec![
+            ots::Attribute{
+                name: ots::Name::new("attr"),
+                value: ots::AttrValue::Int(123),
+                timestamp: ots::AttrTimestamp::ClientAttach(ots::DateTime::now()),
+            }
+        ],
+    };
+    let res = async {
+        let before = client.consumed_capacity();
+        let req = ots::PutRowRequest::new(table_name.clone(), oracle.clone())?;
+        let put = client.put_row(req).await?.consumed;
+        let req = ots::GetRowRequest::new(table_name.clone(), row_key("exist"))?;
+        let resp = client.get_row(req).await?;
+        let exist = resp.row;
+        let get = resp.consumed;
+        let req = ots::GetRowRequest::new(table_name.clone(), row_key("absent"))?;
+        let absent = client.get_row(req).await?.row;
+        let after = client.consumed_capacity();
+        Ok::<_, ots::Error>((exist, absent, put, get, before, after))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (exist, absent, put, get, before, after) = res?;
+    assert_eq!(exist, Some(oracle));
+    assert_eq!(absent, None);
+    assert_eq!(put.write, 1);
+    assert_eq!(get.read, 1);
+    assert!(after.write > before.write);
+    assert!(after.read > before.read);
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_update_get() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "put_update_get".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = ots::RowKey::new(vec![ots::RowKeyColumn{
+        name: ots::Name::new("pkey"),
+        value: ots::RowKeyValue::Str("exist".to_string()),
+    }]);
+    let attr = |name: &str, value: i64| {
+        ots::Attribute{
+            name: ots::Name::new(name),
+            value: ots::AttrValue::Int(value),
+            timestamp: ots::AttrTimestamp::ClientAttach(ots::DateTime::now()),
+        }
+    };
+    let kept = attr("kept", 2);
+    let res = async {
+        let row = ots::Row{
+            row_key: row_key.clone(),
+            attrs: vec![attr("dropped", 1)],
+        };
+        let req = ots::PutRowRequest::new(table_name.clone(), row)?;
+        let _resp = client.put_row(req).await?;
+        let change = ots::RowChange::new(row_key.clone())
+            .put(kept.clone())
+            .delete_all_versions("dropped");
+        let req = ots::UpdateRowRequest::new(table_name.clone(), change)?;
+        let _resp = client.update_row(req).await?;
+        let req = ots::GetRowRequest::new(table_name.clone(), row_key.clone())?;
+        let resp = client.get_row(req).await?;
+        Ok::<_, ots::Error>(resp.row)
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let trial = res?;
+    let oracle = ots::Row{
+        row_key,
+        attrs: vec![kept],
+    };
+    assert_eq!(trial, Some(oracle));
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_delete_get() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "put_delete_get".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = ots::RowKey::new(vec![ots::RowKeyColumn{
+        name: ots::Name::new("pkey"),
+        value: ots::RowKeyValue::Str("exist".to_string()),
+    }]);
+    let res = async {
+        let row = ots::Row{
+            row_key: row_key.clone(),
+            attrs: vec![ots::Attribute{
+                name: ots::Name::new("attr"),
+                value: ots::AttrValue::Int(123),
+                timestamp: ots::AttrTimestamp::ServerAttach,
+            }],
+        };
+        let req = ots::PutRowRequest::new(table_name.clone(), row)?;
+        let _resp = client.put_row(req).await?;
+        let mut req = ots::DeleteRowRequest::new(table_name.clone(), row_key.clone())?;
+        req.condition = ots::Condition::new(ots::RowExistenceExpectation::ExpectExist);
+        let _resp = client.delete_row(req.clone()).await?;
+        let again = client.delete_row(req).await;
+        let req = ots::GetRowRequest::new(table_name.clone(), row_key.clone())?;
+        let resp = client.get_row(req).await?;
+        Ok::<_, ots::Error>((again, resp.row))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (again, trial) = res?;
+    match again {
+        Err(ots::Error{code: ots::ErrorCode::OTSConditionCheckFail, ..}) => {}
+        x => panic!("expect condition check failure, got {:?}", x),
+    }
+    assert_eq!(trial, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_range() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "get_range".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = |x: &str| {
+        ots::RowKey::new(vec![ots::RowKeyColumn{
+            name: ots::Name::new("pkey"),
+            value: ots::RowKeyValue::Str(x.to_string()),
+        }])
+    };
+    let inf = |x: ots::ExtendedRowKeyValue| {
+        ots::ExtendedRowKey::new(vec![ots::ExtendedRowKeyColumn{
+            name: ots::Name::new("pkey"),
+            value: x,
+        }])
+    };
+    let keys = ["a", "b", "c"];
+    let res = async {
+        for k in keys.iter() {
+            let row = ots::Row{
+                row_key: row_key(k),
+                attrs: vec![ots::Attribute{
+                    name: ots::Name::new("attr"),
+                    value: ots::AttrValue::Str(k.to_string()),
+                    timestamp: ots::AttrTimestamp::ServerAttach,
+                }],
+            };
+            let req = ots::PutRowRequest::new(table_name.clone(), row)?;
+            let _resp = client.put_row(req).await?;
+        }
+        let collect = |req: ots::GetRangeRequest| {
+            client.get_range(req)
+                .map(|x| {
+                    x.map(|x| x.row_key)
+                })
+                .collect::<Result<Vec<_>, ots::Error>>()
+        };
+        let req = ots::GetRangeRequest::new(
+            table_name.clone(),
+            inf(ots::ExtendedRowKeyValue::InfMin),
+            inf(ots::ExtendedRowKeyValue::InfMax))?;
+        let forward = collect(req.clone()).await?;
+        let mut limited = req.clone();
+        limited.limit = Some(2);
+        let limited = collect(limited).await?;
+        let backward = ots::GetRangeRequest{
+            direction: ots::Direction::Backward,
+            inclusive_start: inf(ots::ExtendedRowKeyValue::InfMax),
+            exclusive_end: inf(ots::ExtendedRowKeyValue::InfMin),
+            ..req
+        };
+        let backward = collect(backward).await?;
+        Ok::<_, ots::Error>((forward, limited, backward))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (forward, limited, backward) = res?;
+    assert_eq!(forward, vec![row_key("a"), row_key("b"), row_key("c")]);
+    assert_eq!(limited, vec![row_key("a"), row_key("b")]);
+    assert_eq!(backward, vec![row_key("c"), row_key("b"), row_key("a")]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn batch_write_row() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "batch_write_row".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = |x: &str| {
+        ots::RowKey::new(vec![ots::RowKeyColumn{
+            name: ots::Name::new("pkey"),
+            value: ots::RowKeyValue::Str(x.to_string()),
+        }])
+    };
+    let res = async {
+        let mut req = ots::BatchWriteRowRequest::new();
+        req.resubmit_failed_rows = true;
+        let row = ots::Row{
+            row_key: row_key("put"),
+            attrs: vec![ots::Attribute{
+                name: ots::Name::new("attr"),
+                value: ots::AttrValue::Int(1),
+                timestamp: ots::AttrTimestamp::ServerAttach,
+            }],
+        };
+        req.push(&table_name, ots::RowInBatchWrite::new(ots::WriteOperation::Put(row)));
+        let mut expect_exist = ots::RowInBatchWrite::new(
+            ots::WriteOperation::Delete(row_key("absent")));
+        expect_exist.condition = ots::Condition::new(ots::RowExistenceExpectation::ExpectExist);
+        req.push(&table_name, expect_exist);
+        let resp = client.batch_write_row(req).await?;
+        Ok::<_, ots::Error>(resp)
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let resp = res?;
+    assert_eq!(resp.tables.len(), 1);
+    let rows = &resp.tables[0].rows;
+    assert_eq!(rows.len(), 2);
+    assert!(rows[0].is_ok());
+    match &rows[1] {
+        Err(ots::Error{code: ots::ErrorCode::OTSConditionCheckFail, ..}) => {}
+        x => panic!("expect condition check failure, got {:?}", x),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn batch_get_row() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "batch_get_row".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = |x: &str| {
+        ots::RowKey::new(vec![ots::RowKeyColumn{
+            name: ots::Name::new("pkey"),
+            value: ots::RowKeyValue::Str(x.to_string()),
+        }])
+    };
+    let oracle = ots::Row{
+        row_key: row_key("exist"),
+        attrs: vec![ots::Attribute{
+            name: ots::Name::new("attr"),
+            value: ots::AttrValue::Int(123),
+            timestamp: ots::AttrTimestamp::ClientAttach(ots::DateTime::now()),
+        }],
+    };
+    let res = async {
+        let req = ots::PutRowRequest::new(table_name.clone(), oracle.clone())?;
+        let _resp = client.put_row(req).await?;
+        let mut req = ots::BatchGetRowRequest::new();
+        req.tables.push(ots::TableInBatchGet::new(
+            table_name.clone(),
+            vec![row_key("absent"), row_key("exist")]));
+        let resp = client.batch_get_row(req).await?;
+        Ok::<_, ots::Error>(resp)
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let resp = res?;
+    assert_eq!(resp.tables.len(), 1);
+    let rows = &resp.tables[0].rows;
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].as_ref().unwrap(), &None);
+    assert_eq!(rows[1].as_ref().unwrap(), &Some(oracle));
+    Ok(())
+}
+
+#[tokio::test]
+async fn parallel_scan() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "parallel_scan".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = |x: &str| {
+        ots::RowKey::new(vec![ots::RowKeyColumn{
+            name: ots::Name::new("pkey"),
+            value: ots::RowKeyValue::Str(x.to_string()),
+        }])
+    };
+    let inf = |x: ots::ExtendedRowKeyValue| {
+        ots::ExtendedRowKey::new(vec![ots::ExtendedRowKeyColumn{
+            name: ots::Name::new("pkey"),
+            value: x,
+        }])
+    };
+    let keys = ["a", "b", "c"];
+    let res = async {
+        for k in keys.iter() {
+            let row = ots::Row{
+                row_key: row_key(k),
+                attrs: vec![ots::Attribute{
+                    name: ots::Name::new("attr"),
+                    value: ots::AttrValue::Str(k.to_string()),
+                    timestamp: ots::AttrTimestamp::ServerAttach,
+                }],
+            };
+            let req = ots::PutRowRequest::new(table_name.clone(), row)?;
+            let _resp = client.put_row(req).await?;
+        }
+        let splits = client.compute_split_points(table_name.clone(), 1).await?;
+        let req = ots::GetRangeRequest::new(
+            table_name.clone(),
+            inf(ots::ExtendedRowKeyValue::InfMin),
+            inf(ots::ExtendedRowKeyValue::InfMax))?;
+        let rows = client.parallel_scan(req, 1, 2)
+            .map(|x| {
+                x.map(|x| x.row_key)
+            })
+            .collect::<Result<Vec<_>, ots::Error>>()
+            .await?;
+        Ok::<_, ots::Error>((splits, rows))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (splits, mut rows) = res?;
+    assert!(!splits.ranges.is_empty());
+    assert_eq!(splits.ranges[0].0, inf(ots::ExtendedRowKeyValue::InfMin));
+    assert_eq!(splits.ranges.last().unwrap().1, inf(ots::ExtendedRowKeyValue::InfMax));
+    rows.sort_by_key(|x| {
+        format!("{:?}", x)
+    });
+    assert_eq!(rows, vec![row_key("a"), row_key("b"), row_key("c")]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn put_auto_increment() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "put_auto_increment".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("part"),
+                    type_: ots::PkeyValueType::Str,
+                },
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("id"),
+                    type_: ots::PkeyValueType::Int(ots::PkeyIntTypeOption{
+                        auto_increment: true,
+                    }),
+                },
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let res = async {
+        let row = ots::Row{
+            row_key: ots::RowKey::new(vec![
+                ots::RowKeyColumn{
+                    name: ots::Name::new("part"),
+                    value: ots::RowKeyValue::Str("p".to_string()),
+                },
+                ots::RowKeyColumn{
+                    name: ots::Name::new("id"),
+                    value: ots::RowKeyValue::AutoIncr,
+                },
+            ]),
+            attrs: vec![ots::Attribute{
+                name: ots::Name::new("attr"),
+                value: ots::AttrValue::Int(123),
+                timestamp: ots::AttrTimestamp::ServerAttach,
+            }],
+        };
+        let mut req = ots::PutRowRequest::new(table_name.clone(), row)?;
+        req.in_return = ots::InReturn::RowKey;
+        let resp = client.put_row(req).await?;
+        let row_key = match resp.row {
+            Some(x) => x.row_key,
+            None => panic!("expect a returned row key"),
+        };
+        let req = ots::GetRowRequest::new(table_name.clone(), row_key.clone())?;
+        let resp = client.get_row(req).await?;
+        Ok::<_, ots::Error>((row_key, resp.row))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (row_key, row) = res?;
+    let cols: Vec<_> = row_key.iter().collect();
+    assert_eq!(cols.len(), 2);
+    match &cols[1].value {
+        ots::RowKeyValue::Int(_) => {}
+        x => panic!("expect an assigned id, got {:?}", x),
+    }
+    assert_eq!(row.map(|x| x.row_key), Some(row_key));
+    Ok(())
+}
+
+#[tokio::test]
+async fn conditional_update() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "conditional_update".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = ots::RowKey::new(vec![ots::RowKeyColumn{
+        name: ots::Name::new("pkey"),
+        value: ots::RowKeyValue::Str("job".to_string()),
+    }]);
+    let status = |value: &str| {
+        ots::Attribute{
+            name: ots::Name::new("status"),
+            value: ots::AttrValue::Str(value.to_string()),
+            timestamp: ots::AttrTimestamp::ServerAttach,
+        }
+    };
+    let status_is = |value: &str| {
+        ots::Filter::column("status", ots::Comparator::Equal, ots::AttrValue::Str(value.to_string()))
+    };
+    let res = async {
+        let row = ots::Row{
+            row_key: row_key.clone(),
+            attrs: vec![status("running")],
+        };
+        let req = ots::PutRowRequest::new(table_name.clone(), row)?;
+        let _resp = client.put_row(req).await?;
+
+        let change = ots::RowChange::new(row_key.clone())
+            .put(status("done"));
+        let mut req = ots::UpdateRowRequest::new(table_name.clone(), change)?;
+        req.condition.column_condition = Some(status_is("running"));
+        let _resp = client.update_row(req.clone()).await?;
+        let again = client.update_row(req).await.map(|_| ());
+
+        let mut req = ots::GetRowRequest::new(table_name.clone(), row_key.clone())?;
+        req.filter = Some(status_is("done"));
+        let done = client.get_row(req).await?.row;
+        let mut req = ots::GetRowRequest::new(table_name.clone(), row_key.clone())?;
+        req.filter = Some(status_is("running"));
+        let running = client.get_row(req).await?.row;
+        Ok::<_, ots::Error>((again, done, running))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (again, done, running) = res?;
+    match again {
+        Err(ots::Error{code: ots::ErrorCode::OTSConditionCheckFail, ..}) => {}
+        x => panic!("expect a failed condition check, got {:?}", x),
+    }
+    assert!(done.is_some());
+    assert_eq!(running, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn multi_version_get() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "multi_version_get".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.options.max_versions = ots::SettableValue::Value(3);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = ots::RowKey::new(vec![ots::RowKeyColumn{
+        name: ots::Name::new("pkey"),
+        value: ots::RowKeyValue::Str("audited".to_string()),
+    }]);
+    let now = chrono::Utc::now();
+    let at = |secs_ago: i64| -> ots::DateTime {
+        (now - chrono::Duration::seconds(secs_ago)).into()
+    };
+    let version = |value: i64, tm: ots::DateTime| {
+        ots::Attribute{
+            name: ots::Name::new("audit"),
+            value: ots::AttrValue::Int(value),
+            timestamp: ots::AttrTimestamp::ClientAttach(tm),
+        }
+    };
+    let oracle = vec![version(1, at(30)), version(2, at(20)), version(3, at(10))];
+    let res = async {
+        let row = ots::Row{
+            row_key: row_key.clone(),
+            attrs: oracle.clone(),
+        };
+        let req = ots::PutRowRequest::new(table_name.clone(), row)?;
+        let _resp = client.put_row(req).await?;
+
+        let mut req = ots::GetRowRequest::new(table_name.clone(), row_key.clone())?;
+        req.max_versions = Some(3);
+        req.time_range = Some(ots::TimeRange::range(at(60), at(0)));
+        let all = client.get_row(req).await?.row;
+        let mut req = ots::GetRowRequest::new(table_name.clone(), row_key.clone())?;
+        req.max_versions = None;
+        req.time_range = Some(ots::TimeRange::specific(at(20)));
+        let specific = client.get_row(req).await?.row;
+        Ok::<_, ots::Error>((all, specific))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (all, specific) = res?;
+    let all = all.map(|x| x.versions());
+    assert_eq!(all, Some(vec![(ots::Name::new("audit"), oracle.clone())]));
+    assert_eq!(specific.map(|x| x.attrs), Some(vec![oracle[1].clone()]));
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_wide_row() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "get_wide_row".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = ots::RowKey::new(vec![ots::RowKeyColumn{
+        name: ots::Name::new("pkey"),
+        value: ots::RowKeyValue::Str("wide".to_string()),
+    }]);
+    let res = async {
+        let row = ots::Row{
+            row_key: row_key.clone(),
+            attrs: (0..10)
+                .map(|i| {
+                    ots::Attribute{
+                        name: ots::Name::new(format!("c{}", i)),
+                        value: ots::AttrValue::Int(i),
+                        timestamp: ots::AttrTimestamp::ServerAttach,
+                    }
+                })
+                .collect(),
+        };
+        let req = ots::PutRowRequest::new(table_name.clone(), row)?;
+        let _resp = client.put_row(req).await?;
+
+        let mut req = ots::GetRowRequest::new(table_name.clone(), row_key.clone())?;
+        req.start_column = Some(ots::Name::new("c2"));
+        req.end_column = Some(ots::Name::new("c5"));
+        let chunks: Vec<_> = client.get_wide_row(req).collect().await;
+        Ok::<_, ots::Error>(chunks)
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let mut trial = vec![];
+    for chunk in res? {
+        let chunk = chunk?;
+        assert_eq!(chunk.row_key, row_key);
+        trial.extend(chunk.attrs.into_iter().map(|x| x.value));
+    }
+    assert_eq!(trial, vec![
+        ots::AttrValue::Int(2),
+        ots::AttrValue::Int(3),
+        ots::AttrValue::Int(4),
+    ]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_increment() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "update_increment".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("pkey"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let row_key = ots::RowKey::new(vec![ots::RowKeyColumn{
+        name: ots::Name::new("pkey"),
+        value: ots::RowKeyValue::Str("counter".to_string()),
+    }]);
+    let res = async {
+        let mut values = vec![];
+        for delta in &[1, 2, -4] {
+            let change = ots::RowChange::new(row_key.clone())
+                .increment("hits", *delta);
+            let mut req = ots::UpdateRowRequest::new(table_name.clone(), change)?;
+            req.in_return = ots::InReturn::AfterModify(vec![ots::Name::new("hits")]);
+            let resp = client.update_row(req).await?;
+            let attrs = resp.row.map(|x| x.attrs).unwrap_or_default();
+            values.extend(attrs.into_iter().map(|x| x.value));
+        }
+        Ok::<_, ots::Error>(values)
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let trial = res?;
+    assert_eq!(trial, vec![
+        ots::AttrValue::Int(1),
+        ots::AttrValue::Int(3),
+        ots::AttrValue::Int(-1),
+    ]);
+    Ok(())
+}
@@ -42,3 +42,233 @@ async fn create_delete() -> Result<(), ots::Error> {
     assert_eq!((should_in, should_not_in), (true, false));
     Ok(())
 }
+
+#[tokio::test]
+async fn create_describe() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "create_describe".to_string();
+    let meta = ots::TableMeta{
+        name: table_name.clone().into(),
+        schema: vec![
+            ots::PkeyColumnSchema{
+                name: ots::Name::new("haha"),
+                type_: ots::PkeyValueType::Str,
+            }
+        ]
+    };
+    {
+        let mut req = ots::CreateTableRequest::new(meta.clone());
+        req.options.max_versions = ots::SettableValue::Value(3);
+        req.options.bloom_filter_type = ots::SettableValue::Value(ots::BloomFilterType::Cell);
+        let _resp = client.create_table(req).await?;
+    }
+    let res = client.describe_table(table_name.clone()).await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let desc = res?.description;
+    assert_eq!(desc.table_meta, meta);
+    assert_eq!(desc.options.max_versions, ots::SettableValue::Value(3));
+    assert_eq!(
+        desc.options.bloom_filter_type,
+        ots::SettableValue::Value(ots::BloomFilterType::Cell));
+    assert!(desc.shard_splits.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_update_describe() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "create_update_describe".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("haha"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let req = ots::CreateTableRequest::new(meta);
+        let _resp = client.create_table(req).await?;
+    }
+    let res = async {
+        let mut req = ots::UpdateTableRequest::new(table_name.clone());
+        req.options.max_versions = ots::SettableValue::Value(5);
+        req.options.time_to_live = ots::SettableValue::Value(chrono::Duration::days(1));
+        req.options.bloom_filter_type = ots::SettableValue::Value(ots::BloomFilterType::Row);
+        req.options.block_size = ots::SettableValue::Value(16384);
+        let updated = client.update_table(req).await?;
+        let described = client.describe_table(table_name.clone()).await?;
+        Ok::<_, ots::Error>((updated, described))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (updated, described) = res?;
+    assert_eq!(updated.options.max_versions, ots::SettableValue::Value(5));
+    assert_eq!(
+        updated.options.time_to_live,
+        ots::SettableValue::Value(chrono::Duration::days(1)));
+    assert_eq!(
+        updated.options.bloom_filter_type,
+        ots::SettableValue::Value(ots::BloomFilterType::Row));
+    assert_eq!(updated.options.block_size, ots::SettableValue::Value(16384));
+    assert_eq!(described.description.options, updated.options);
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_wait_until_active() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "create_wait_until_active".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("haha"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let res = async {
+        let waited = client.wait_until_active(
+            table_name.clone(),
+            std::time::Duration::from_secs(1)).await?;
+        let described = client.describe_table(table_name.clone()).await?;
+        Ok::<_, ots::Error>((waited, described))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (waited, described) = res?;
+    assert_eq!(waited.status, ots::TableStatus::Active);
+    assert_eq!(described.description.status, ots::TableStatus::Active);
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_presplit() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "create_presplit".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("haha"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.split_points = vec![
+            ots::RowKeyValue::Str("g".to_string()),
+            ots::RowKeyValue::Str("p".to_string()),
+        ];
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let res = client.describe_table(table_name.clone()).await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let desc = res?.description;
+    let split = |x: &str| {
+        ots::RowKey::new(vec![ots::RowKeyColumn{
+            name: ots::Name::new("haha"),
+            value: ots::RowKeyValue::Str(x.to_string()),
+        }])
+    };
+    assert_eq!(desc.shard_splits, vec![split("g"), split("p")]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn unload_load() -> Result<(), ots::Error> {
+    let (ep, cred) = fetch_endpoint_credential()?;
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let table_name = "unload_load".to_string();
+    {
+        let meta = ots::TableMeta{
+            name: table_name.clone().into(),
+            schema: vec![
+                ots::PkeyColumnSchema{
+                    name: ots::Name::new("haha"),
+                    type_: ots::PkeyValueType::Str,
+                }
+            ]
+        };
+        let mut req = ots::CreateTableRequest::new(meta);
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.create_table(req).await?;
+    }
+    let res = async {
+        let mut req = ots::UnloadTableRequest::new(table_name.clone());
+        req.wait_until_inactive = Some(std::time::Duration::from_secs(60));
+        let _resp = client.unload_table(req).await?;
+        let unloaded = client.describe_table(table_name.clone()).await?;
+        let mut req = ots::LoadTableRequest::new(table_name.clone());
+        req.wait_until_active = Some(std::time::Duration::from_secs(60));
+        let _resp = client.load_table(req).await?;
+        let loaded = client.describe_table(table_name.clone()).await?;
+        Ok::<_, ots::Error>((unloaded, loaded))
+    }.await;
+    let _resp = client.delete_table(table_name.clone()).await?;
+
+    let (unloaded, loaded) = res?;
+    assert_eq!(unloaded.description.status, ots::TableStatus::Inactive);
+    assert_eq!(loaded.description.status, ots::TableStatus::Active);
+    Ok(())
+}
+
+#[cfg(feature = "rustls")]
+#[tokio::test]
+async fn list_table_over_https() -> Result<(), ots::Error> {
+    let (mut ep, cred) = fetch_endpoint_credential()?;
+    ep.address = ep.address.replacen("http://", "https://", 1);
+    let opts = ots::ClientOptions::default();
+    let client = ots::Client::new(ep, cred, opts)?;
+    let _resp = client.list_table().await?;
+    Ok(())
+}
+
+#[cfg(feature = "rustls")]
+#[tokio::test]
+async fn https_rejects_empty_ca_bundle() {
+    let ep = ots::Endpoint::new("https://localhost", "instance").unwrap();
+    let cred = ots::Credential::new("ak", "secret").unwrap();
+    let opts = ots::ClientOptions{
+        tls_ca_certs: Some(vec![]),
+        ..Default::default()
+    };
+    match ots::Client::new(ep, cred, opts) {
+        Err(ots::Error{code: ots::ErrorCode::ClientUnknown, ..}) => {}
+        Err(err) => panic!("unexpected {:?}", err),
+        Ok(_) => panic!("expect an error"),
+    }
+}
+
+#[cfg(not(feature = "rustls"))]
+#[tokio::test]
+async fn https_requires_rustls() {
+    let ep = ots::Endpoint::new("https://localhost", "instance").unwrap();
+    let cred = ots::Credential::new("ak", "secret").unwrap();
+    let opts = ots::ClientOptions::default();
+    match ots::Client::new(ep, cred, opts) {
+        Err(ots::Error{code: ots::ErrorCode::ClientUnknown, ..}) => {}
+        Err(err) => panic!("unexpected {:?}", err),
+        Ok(_) => panic!("expect an error"),
+    }
+}
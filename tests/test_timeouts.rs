@@ -0,0 +1,84 @@
+use tablestore as ots;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpListener;
+
+// a server which accepts connections but never responds
+async fn silent_server() -> (ots::Endpoint, TcpListener) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let ep = ots::Endpoint::new(format!("http://{}", addr), "silent").unwrap();
+    (ep, listener)
+}
+
+fn credential() -> ots::Credential {
+    ots::Credential::new("id", "secret").unwrap()
+}
+
+#[tokio::test]
+async fn attempt_and_total_timeouts() -> Result<(), ots::Error> {
+    let (ep, _listener) = silent_server().await;
+    let client = ots::Client::new(ep, credential(), ots::ClientOptions::default())?;
+    let client = client.with_timeouts(ots::Timeouts{
+        attempt: Some(Duration::from_millis(100)),
+        total: Some(Duration::from_millis(500)),
+        ..client.timeouts()
+    });
+    let start = Instant::now();
+    let res = client.list_table().await;
+    match res {
+        Err(ots::Error{code: ots::ErrorCode::OperationTimeout, ..}) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    assert!(start.elapsed() < Duration::from_secs(2));
+    Ok(())
+}
+
+#[tokio::test]
+async fn cancel_on_drop() -> Result<(), ots::Error> {
+    let (ep, mut listener) = silent_server().await;
+    let opts = ots::ClientOptions{
+        timeouts: ots::Timeouts{
+            attempt: None,
+            total: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let client = ots::Client::new(ep, credential(), opts)?;
+    let res = tokio::time::timeout(Duration::from_millis(100), client.list_table()).await;
+    assert!(res.is_err());
+
+    // the in-flight request is dropped, so is its connection
+    let (mut conn, _) = listener.accept().await.unwrap();
+    let mut buf = vec![0u8; 4096];
+    let closed = tokio::time::timeout(Duration::from_secs(2), async {
+        while conn.read(&mut buf).await.unwrap() > 0 {}
+    }).await;
+    assert!(closed.is_ok());
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore] // needs a network which drops packets to 10.255.255.1
+async fn connect_timeout() -> Result<(), ots::Error> {
+    // non-routable, so that connecting hangs, and is retried until the total timeout
+    let ep = ots::Endpoint::new("http://10.255.255.1", "unroutable")?;
+    let opts = ots::ClientOptions{
+        timeouts: ots::Timeouts{
+            connect: Some(Duration::from_millis(100)),
+            attempt: None,
+            total: Some(Duration::from_secs(1)),
+        },
+        ..Default::default()
+    };
+    let client = ots::Client::new(ep, credential(), opts)?;
+    let start = Instant::now();
+    let res = client.list_table().await;
+    match res {
+        Err(ots::Error{code: ots::ErrorCode::OperationTimeout, ..}) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    assert!(start.elapsed() < Duration::from_secs(2));
+    Ok(())
+}
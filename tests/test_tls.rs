@@ -0,0 +1,105 @@
+#![cfg(feature = "rustls")]
+use tablestore as ots;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_rustls::rustls;
+
+const CA_CERT: &[u8] = include_bytes!("tls/ca.pem");
+const SERVER_CERT: &[u8] = include_bytes!("tls/server.pem");
+const SERVER_KEY: &[u8] = include_bytes!("tls/server.key");
+
+// a TLS server for "localhost", whose certificate is issued by CA_CERT.
+// It never handshakes on the first `silent` connections.
+async fn serve(silent: usize) -> (ots::Endpoint, Arc<AtomicUsize>) {
+    let certs = rustls::internal::pemfile::certs(&mut std::io::BufReader::new(SERVER_CERT)).unwrap();
+    let mut keys = rustls::internal::pemfile::pkcs8_private_keys(&mut std::io::BufReader::new(SERVER_KEY)).unwrap();
+    let mut config = rustls::ServerConfig::new(rustls::NoClientAuth::new());
+    config.set_single_cert(certs, keys.remove(0)).unwrap();
+    let acceptor = tokio_rustls::TlsAcceptor::from(Arc::new(config));
+
+    let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let conns = Arc::new(AtomicUsize::new(0));
+    let counter = conns.clone();
+    tokio::spawn(async move {
+        let mut held = vec![];
+        loop {
+            let (conn, _) = listener.accept().await.unwrap();
+            if counter.fetch_add(1, Ordering::Relaxed) < silent {
+                held.push(conn);
+                continue;
+            }
+            let acceptor = acceptor.clone();
+            tokio::spawn(async move {
+                let conn = match acceptor.accept(conn).await {
+                    Ok(x) => x,
+                    Err(_) => return,
+                };
+                let svc = hyper::service::service_fn(|_req| async {
+                    Ok::<_, Infallible>(hyper::Response::new(hyper::Body::empty()))
+                });
+                let _ = hyper::server::conn::Http::new().serve_connection(conn, svc).await;
+            });
+        }
+    });
+    let ep = ots::Endpoint::new(format!("https://localhost:{}", addr.port()), "local").unwrap();
+    (ep, conns)
+}
+
+fn credential() -> ots::Credential {
+    ots::Credential::new("id", "secret").unwrap()
+}
+
+#[tokio::test]
+async fn custom_ca_certs() -> Result<(), ots::Error> {
+    let (ep, _) = serve(0).await;
+    let opts = ots::ClientOptions{
+        tls_ca_certs: Some(CA_CERT.to_vec()),
+        ..Default::default()
+    };
+    let client = ots::Client::new(ep, credential(), opts)?;
+    client.list_table().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn untrusted_server() -> Result<(), ots::Error> {
+    let (ep, _) = serve(0).await;
+    let client = ots::Client::new(ep, credential(), ots::ClientOptions::default())?;
+    let client = client.with_timeouts(ots::Timeouts{
+        total: Some(Duration::from_secs(2)), // SslHandshakeFail is not retried
+        ..client.timeouts()
+    });
+    match client.list_table().await {
+        Err(ots::Error{code: ots::ErrorCode::SslHandshakeFail, ..}) => {}
+        x => panic!("unexpected {:?}", x),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn write_retried_after_connect_timeout() -> Result<(), ots::Error> {
+    let (ep, conns) = serve(1).await;
+    let opts = ots::ClientOptions{
+        tls_ca_certs: Some(CA_CERT.to_vec()),
+        timeouts: ots::Timeouts{
+            connect: Some(Duration::from_millis(100)), // TLS handshake included
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let client = ots::Client::new(ep, credential(), opts)?;
+    let row = ots::Row{
+        row_key: ots::RowKey::new(vec![ots::RowKeyColumn{
+            name: ots::Name::new("pkey"),
+            value: ots::RowKeyValue::Int(0),
+        }]),
+        attrs: vec![],
+    };
+    client.put_row(ots::PutRowRequest::new("table", row)?).await?;
+    assert_eq!(conns.load(Ordering::Relaxed), 2);
+    Ok(())
+}